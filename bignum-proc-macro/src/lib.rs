@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{
     parse::{self, Parse},
-    parse_macro_input, Ident, Lit, Token, Visibility,
+    parse_macro_input, Expr, ExprLit, Ident, Lit, Path, Token, Visibility,
 };
 
 struct BaseData {
@@ -29,22 +29,224 @@ impl Parse for BaseInput {
     }
 }
 
-/// Called like create_efficient_base(n, (pub) IntName), where n is the number of the base
-/// and IntName is the name of the type you want to create, with optional visibility
-/// qualifiers
+enum BignumInput {
+    Type(BaseInput),
+    Value {
+        base: Path,
+        value: Lit,
+    },
+    Parts {
+        base: Path,
+        sig: Box<Expr>,
+        exp: Box<Expr>,
+    },
+}
+
+impl Parse for BignumInput {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Lit) {
+            return Ok(Self::Type(input.parse()?));
+        }
+
+        let base = input.parse()?;
+        let _com: Token![,] = input.parse()?;
+        let first = input.parse()?;
+
+        if input.is_empty() {
+            match first {
+                Expr::Lit(ExprLit { lit, .. }) => Ok(Self::Value { base, value: lit }),
+                _ => Err(syn::Error::new_spanned(
+                    first,
+                    "Expected a literal value, or a significand and exponent",
+                )),
+            }
+        } else {
+            let _com: Token![,] = input.parse()?;
+            let exp = input.parse()?;
+
+            Ok(Self::Parts {
+                base,
+                sig: Box::new(first),
+                exp,
+            })
+        }
+    }
+}
+
+/// Has three forms:
+/// - `make_bignum!(n, (pub) IntName)` creates an efficient base for the number `n` (see
+///   `create_efficient_base`) along with a `BigNumBase` alias called `IntName`, with
+///   optional visibility qualifiers
+/// - `make_bignum!(Base, sig, exp)` creates a value from a significand and exponent,
+///   which must already be normalized (see `BigNumBase::new_raw`)
+/// - `make_bignum!(Base, literal)` creates a value from an integer or float literal, e.g.
+///   `make_bignum!(Decimal, 1.5e40)`. Anything after the decimal point is dropped
+///
+/// For the built-in bases (`Binary`, `Octal`, `Decimal` and `Hexadecimal`) the value forms
+/// are checked at compile time and expand to a constant expression, so they can be used
+/// to initialize a `const`. In the `(Base, sig, exp)` form `sig` and `exp` can be any
+/// constant expressions, e.g. `1 << 63`. Float literals are exact for `Decimal`, for the
+/// other built-in bases they have to fit in a `u128`. Other bases are passed to
+/// `new_raw`, `from_u128` or `saturating_from_f64` and checked at runtime instead
 #[proc_macro]
 pub fn make_bignum(input: TokenStream) -> TokenStream {
-    let BaseInput { num, vis, name } = parse_macro_input!(input as BaseInput);
-    let (core, base_ident) = create_efficient_base_core(num);
+    match parse_macro_input!(input as BignumInput) {
+        BignumInput::Type(BaseInput { num, vis, name }) => {
+            let (core, base_ident) = create_efficient_base_core(num);
 
-    quote! {
-        #core
+            quote! {
+                #core
+
+                #vis type #name = bignumbe_rs::BigNumBase<#base_ident>;
+            }
+        }
+        BignumInput::Value { base, value } => match builtin_number(&base) {
+            Some(number) => {
+                let (sig, exp) = literal_parts(&value, number);
+                let (sig, exp) = normalize(sig, exp, number);
 
-        #vis type #name = bignumbe_rs::BigNumBase<#base_ident>;
+                quote! {
+                    bignumbe_rs::BigNumBase { sig: #sig, exp: #exp, base: #base }
+                }
+            }
+            None => match value {
+                Lit::Int(li) => {
+                    let value: u128 = li
+                        .base10_parse()
+                        .expect("Integer literals must fit in a u128");
+                    quote! { bignumbe_rs::BigNumBase::<#base>::from_u128(#value) }
+                }
+                Lit::Float(lf) => {
+                    let value: f64 = lf.base10_parse().expect("Invalid float literal");
+                    quote! { bignumbe_rs::BigNumBase::<#base>::saturating_from_f64(#value) }
+                }
+                _ => panic!("make_bignum! values must be integer or float literals"),
+            },
+        },
+        BignumInput::Parts { base, sig, exp } => match builtin_number(&base) {
+            Some(number) => {
+                let BaseData {
+                    sig_range: (min_sig, max_sig),
+                    ..
+                } = get_base_data(number);
+                let msg = format!(
+                    "make_bignum! significand is out of range for base {}, it must be in \
+                     [{}, {}] unless the exponent is 0",
+                    number, min_sig, max_sig
+                );
+
+                quote! {
+                    {
+                        const __SIG: u64 = #sig;
+                        const __EXP: u64 = #exp;
+                        const _: () = assert!(
+                            __SIG <= #max_sig && (__EXP == 0 || __SIG >= #min_sig),
+                            #msg
+                        );
+
+                        bignumbe_rs::BigNumBase { sig: __SIG, exp: __EXP, base: #base }
+                    }
+                }
+            }
+            None => quote! { bignumbe_rs::BigNumBase::<#base>::new_raw(#sig, #exp) },
+        },
     }
     .into()
 }
 
+// The ranges of these are known ahead of time, so their values can be checked during
+// expansion. This goes by name since that's all a macro can see
+fn builtin_number(base: &Path) -> Option<u32> {
+    match base.segments.last()?.ident.to_string().as_str() {
+        "Binary" => Some(2),
+        "Octal" => Some(8),
+        "Decimal" => Some(10),
+        "Hexadecimal" => Some(16),
+        _ => None,
+    }
+}
+
+// Splits an integer or float literal into `(mantissa, exp)` where the value is
+// `mantissa * 10^exp`. Digits that don't fit in a u128 are dropped
+fn parse_decimal(lit: &Lit) -> (u128, i64) {
+    let text = match lit {
+        Lit::Int(li) => li.base10_digits().to_string(),
+        Lit::Float(lf) => lf.base10_digits().to_string(),
+        _ => panic!("make_bignum! values must be integer or float literals"),
+    };
+
+    let (mantissa, exp) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => (mantissa, exp.parse::<i64>().expect("Invalid exponent")),
+        None => (text.as_str(), 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let mut exp = exp - frac.len() as i64;
+    let mut value = 0u128;
+
+    for digit in int.chars().chain(frac.chars()) {
+        let digit = digit.to_digit(10).expect("Invalid digit") as u128;
+
+        match value.checked_mul(10).and_then(|v| v.checked_add(digit)) {
+            Some(v) => value = v,
+            None => exp += 1,
+        }
+    }
+
+    (value, exp)
+}
+
+// Returns `(sig, exp)` with the value `sig * number^exp`, truncating any fractional part
+fn literal_parts(lit: &Lit, number: u32) -> (u128, u64) {
+    let (mantissa, exp) = parse_decimal(lit);
+    let pow10 = |exp: u64| u32::try_from(exp).ok().and_then(|e| 10u128.checked_pow(e));
+
+    if exp < 0 {
+        (pow10(exp.unsigned_abs()).map_or(0, |p| mantissa / p), 0)
+    } else if number == 10 {
+        (mantissa, exp as u64)
+    } else {
+        let value = pow10(exp as u64)
+            .and_then(|p| mantissa.checked_mul(p))
+            .unwrap_or_else(|| {
+                panic!(
+                    "make_bignum! literal is too large to convert to base {}, use the \
+                     (Base, sig, exp) form instead",
+                    number
+                )
+            });
+
+        (value, 0)
+    }
+}
+
+// Does the same normalization as `BigNumBase::new`, but with a u128 significand
+fn normalize(sig: u128, exp: u64, number: u32) -> (u64, u64) {
+    let BaseData {
+        sig_range: (min_sig, max_sig),
+        ..
+    } = get_base_data(number);
+    let (mut sig, mut exp) = (sig, exp);
+
+    while sig > max_sig as u128 {
+        sig /= number as u128;
+        exp = exp
+            .checked_add(1)
+            .expect("make_bignum! literal is too large for a BigNum");
+    }
+
+    if sig == 0 {
+        return (0, 0);
+    }
+
+    while exp > 0 && sig < min_sig as u128 {
+        sig *= number as u128;
+        exp -= 1;
+    }
+
+    (sig as u64, exp)
+}
+
 /// Called like create_efficient_base(n), where n is the number of the base
 #[proc_macro]
 pub fn create_efficient_base(input: TokenStream) -> TokenStream {
@@ -54,11 +256,11 @@ pub fn create_efficient_base(input: TokenStream) -> TokenStream {
 }
 
 fn create_efficient_base_core(lit: Lit) -> (proc_macro2::TokenStream, Ident) {
-    let number: u16 = if let Lit::Int(li) = lit {
+    let number: u32 = if let Lit::Int(li) = lit {
         li.base10_parse()
             .expect("Input must be a valid base-10 number")
     } else {
-        panic!("Input must be a valid u16 value greater than 2");
+        panic!("Input must be a valid u32 value greater than 2");
     };
     let number = number as u64;
 
@@ -69,7 +271,7 @@ fn create_efficient_base_core(lit: Lit) -> (proc_macro2::TokenStream, Ident) {
         sig_range,
         powers,
         powers_u128,
-    } = get_base_data(number as u16);
+    } = get_base_data(number as u32);
 
     let power_tables = generate_power_tables(number, powers, powers_u128);
     let impl_code = generate_impl(number, &base_ident, exp_range, sig_range);
@@ -101,7 +303,7 @@ fn generate_impl(
     let (min_sig, max_sig) = sig_range;
 
     let shared = quote! {
-        const NUMBER: u16 = #number as u16;
+        const NUMBER: u32 = #number as u32;
 
         fn new() -> Self {
             Self()
@@ -122,6 +324,10 @@ fn generate_impl(
         fn pow_u128(exp: u32) -> u128 {
             #powers_u128_ident[exp as usize]
         }
+
+        fn powers() -> Option<&'static [u64]> {
+            Some(&#powers_ident)
+        }
     };
 
     if number.is_power_of_two() {
@@ -198,11 +404,17 @@ fn generate_power_tables(
     }
 }
 
-fn get_base_data(number: u16) -> BaseData {
+fn get_base_data(number: u32) -> BaseData {
+    // Powers of 0 or 1 never overflow so the loop below would never end
+    if number < 2 {
+        panic!("Invalid base {}, bases must be at least 2", number);
+    }
+
     let mut curr = 1u128;
 
-    let mut powers = Vec::new();
-    let mut powers_u128 = Vec::new();
+    // One entry for each power from 0 up to the largest that fits
+    let mut powers = Vec::with_capacity(u64::MAX.ilog(number as u64) as usize + 1);
+    let mut powers_u128 = Vec::with_capacity(u128::MAX.ilog(number as u128) as usize + 1);
 
     loop {
         if curr <= u64::MAX as u128 {
@@ -288,5 +500,58 @@ mod tests {
         test_base!(spec 256, 7);
         test_base!(spec 16, 15);
         test_base!(spec 2, 63);
+
+        let data = get_base_data(100000);
+        assert_eq!(data.exp_range, (2, 3));
+        assert_eq!(data.sig_range, (100000u64.pow(2), 100000u64.pow(3) - 1));
+        assert_eq!(data.powers.len(), 4);
+    }
+
+    #[test]
+    fn get_base_data_capacity_test() {
+        for number in [2, 3, 10, 16, 61, 256, 65535, 100000, u32::MAX] {
+            let data = get_base_data(number);
+
+            assert_eq!(data.powers.capacity(), data.powers.len());
+            assert_eq!(data.powers_u128.capacity(), data.powers_u128.len());
+        }
+    }
+
+    #[test]
+    fn literal_parts_test() {
+        let lit = |s: &str| syn::parse_str::<Lit>(s).unwrap();
+
+        assert_eq!(parse_decimal(&lit("1.5e40")), (15, 39));
+        assert_eq!(parse_decimal(&lit("12_345")), (12345, 0));
+        assert_eq!(parse_decimal(&lit("0x10")), (16, 0));
+        assert_eq!(parse_decimal(&lit("2.25E-1")), (225, -3));
+
+        assert_eq!(literal_parts(&lit("1.5e40"), 10), (15, 39));
+        assert_eq!(literal_parts(&lit("1.5e3"), 2), (1500, 0));
+        assert_eq!(literal_parts(&lit("2.75"), 16), (2, 0));
+        assert_eq!(literal_parts(&lit("1e-50"), 10), (0, 0));
+
+        assert_eq!(normalize(15, 39, 10), (1_500_000_000_000_000_000, 22));
+        assert_eq!(normalize(1500, 0, 2), (1500, 0));
+        assert_eq!(normalize(1 << 70, 0, 2), (1 << 63, 7));
+        assert_eq!(normalize(0, 39, 10), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "too large to convert to base 2")]
+    fn literal_parts_overflow_test() {
+        literal_parts(&syn::parse_str::<Lit>("1e50").unwrap(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid base 1, bases must be at least 2")]
+    fn get_base_data_one_test() {
+        get_base_data(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid base 0, bases must be at least 2")]
+    fn get_base_data_zero_test() {
+        get_base_data(0);
     }
 }
@@ -0,0 +1,355 @@
+//! This module contains wrappers that keep track of the error introduced by arithmetic.
+//! `DriftGuard` keeps a running bound on the relative error of a chain of
+//! multiplications, and `Tracked` counts operations so `fuzzy_eq` margins don't have to be
+//! worked out by hand.
+
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use crate::{Base, BigNumBase};
+
+/// Wraps a value and tracks the worst-case relative error accumulated by multiplying it.
+/// Each multiplication that has to drop digits to normalize can be off by up to one unit
+/// in the last place of the result, so the relative error it adds is at most `1 / sig`.
+/// Relative errors of a product add up, so the bound is the sum over every
+/// multiplication. Multiplications whose exact result fits in the significand add
+/// nothing.
+///
+/// Once `max_error` grows past what you're comfortable with you can recompute the value
+/// from scratch (e.g. with `pow`) and pass it to `reanchor`.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{drift::DriftGuard, BigNumDec};
+///
+/// let mut guard = DriftGuard::new(BigNumDec::from(1));
+///
+/// for _ in 0..100 {
+///     guard *= BigNumDec::from(3);
+/// }
+///
+/// assert!(guard.max_error() > 0.0);
+/// assert!(!guard.needs_reanchor(1e-9));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DriftGuard<T>
+where
+    T: Base,
+{
+    value: BigNumBase<T>,
+    max_error: f64,
+}
+
+impl<T> DriftGuard<T>
+where
+    T: Base,
+{
+    /// Starts tracking from `value`, which is assumed to be exact
+    pub fn new(value: BigNumBase<T>) -> Self {
+        Self {
+            value,
+            max_error: 0.0,
+        }
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> BigNumBase<T> {
+        self.value
+    }
+
+    /// Returns the worst-case relative error of `value()` compared to the exact result of
+    /// every multiplication so far
+    pub fn max_error(&self) -> f64 {
+        self.max_error
+    }
+
+    /// Returns true if the worst-case relative error has passed `threshold`
+    pub fn needs_reanchor(&self, threshold: f64) -> bool {
+        self.max_error > threshold
+    }
+
+    /// Replaces the value with a freshly computed exact one and resets the error bound
+    pub fn reanchor(&mut self, value: BigNumBase<T>) {
+        *self = Self::new(value);
+    }
+}
+
+impl<T> Mul<BigNumBase<T>> for DriftGuard<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: BigNumBase<T>) -> Self::Output {
+        let value = self.value * rhs;
+        let max_sig = value.base.sig_range().max();
+
+        // If the full product fits in a significand nothing was truncated
+        let op_error = if self.value.sig as u128 * rhs.sig as u128 <= max_sig as u128 {
+            0.0
+        } else {
+            1.0 / value.sig as f64
+        };
+
+        Self {
+            value,
+            max_error: self.max_error + op_error,
+        }
+    }
+}
+
+impl<T> MulAssign<BigNumBase<T>> for DriftGuard<T>
+where
+    T: Base,
+{
+    fn mul_assign(&mut self, rhs: BigNumBase<T>) {
+        *self = *self * rhs;
+    }
+}
+
+/// Wraps a value and counts the arithmetic operations that went into it. Since each
+/// operation can be off by 1, the number of operations on both sides is a safe margin for
+/// `fuzzy_eq`, which `could_equal` uses automatically. Combining two tracked values adds
+/// their counts together, plus one for the operation itself.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{drift::Tracked, BigNumDec};
+///
+/// let a = Tracked::new(BigNumDec::new(9_999_999_999_999_999_999, 234));
+/// let b = a + a + a + a + a;
+/// let c = 2 * a + 3 * a;
+///
+/// assert_ne!(b.value(), c.value());
+/// assert!(b.could_equal(c));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Tracked<T>
+where
+    T: Base,
+{
+    value: BigNumBase<T>,
+    ops: u64,
+}
+
+impl<T> Tracked<T>
+where
+    T: Base,
+{
+    /// Starts tracking from `value`, which is assumed to be exact
+    pub fn new(value: BigNumBase<T>) -> Self {
+        Self { value, ops: 0 }
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> BigNumBase<T> {
+        self.value
+    }
+
+    /// Returns the number of operations performed to get the current value
+    pub fn ops(&self) -> u64 {
+        self.ops
+    }
+
+    /// Returns true if the values could be equal given the error their operations could
+    /// have introduced. This is `fuzzy_eq` with the combined operation count as a margin
+    pub fn could_equal(self, other: Self) -> bool {
+        self.value
+            .fuzzy_eq(other.value, self.ops.saturating_add(other.ops))
+    }
+}
+
+impl<T> From<BigNumBase<T>> for Tracked<T>
+where
+    T: Base,
+{
+    fn from(value: BigNumBase<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! impl_tracked_ops {
+    ($(($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident)),+) => {
+        $(
+            impl<T> $trait for Tracked<T>
+            where
+                T: Base,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Self {
+                        value: $trait::$method(self.value, rhs.value),
+                        ops: self.ops.saturating_add(rhs.ops).saturating_add(1),
+                    }
+                }
+            }
+
+            impl<T> $trait<BigNumBase<T>> for Tracked<T>
+            where
+                T: Base,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: BigNumBase<T>) -> Self::Output {
+                    $trait::$method(self, Self::new(rhs))
+                }
+            }
+
+            impl<T> $trait<u64> for Tracked<T>
+            where
+                T: Base,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: u64) -> Self::Output {
+                    $trait::$method(self, Self::new(BigNumBase::from(rhs)))
+                }
+            }
+
+            impl<T> $trait<Tracked<T>> for u64
+            where
+                T: Base,
+            {
+                type Output = Tracked<T>;
+
+                fn $method(self, rhs: Tracked<T>) -> Self::Output {
+                    $trait::$method(Tracked::new(BigNumBase::from(self)), rhs)
+                }
+            }
+
+            impl<T> $assign_trait for Tracked<T>
+            where
+                T: Base,
+            {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )+
+    };
+}
+impl_tracked_ops!(
+    (Add, add, AddAssign, add_assign),
+    (Sub, sub, SubAssign, sub_assign),
+    (Mul, mul, MulAssign, mul_assign),
+    (Div, div, DivAssign, div_assign)
+);
+
+impl<T> Sum for Tracked<T>
+where
+    T: Base,
+{
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        if let Some(elem) = iter.next() {
+            iter.fold(elem, |acc, n| acc + n)
+        } else {
+            Self::new(BigNumBase::from(0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{BigNumBin, BigNumDec};
+
+    #[test]
+    fn drift_guard_test() {
+        type BigNum = BigNumBin;
+
+        let factor = BigNum::from(3);
+        let mut guard = DriftGuard::new(BigNum::from(1));
+        let mut prev_error = 0.0;
+
+        for i in 1..=1000 {
+            guard *= factor;
+
+            assert!(guard.max_error() >= prev_error);
+            prev_error = guard.max_error();
+
+            // 3^40 still fits in a u64 so there's no truncation until then
+            if i <= 40 {
+                assert_eq!(guard.max_error(), 0.0);
+            }
+        }
+
+        let exact = factor.pow(1000);
+        let (min, max) = if exact > guard.value() {
+            (guard.value(), exact)
+        } else {
+            (exact, guard.value())
+        };
+
+        // `pow` does up to 20 multiplications of its own, so allow for its error as well
+        let pow_error = 20.0 / (1u64 << 63) as f64;
+        let actual_error = if max == min {
+            0.0
+        } else {
+            1.0 / (max / (max - min)).sig as f64
+        };
+
+        assert!(guard.max_error() > 0.0);
+        assert!(actual_error <= guard.max_error() + pow_error);
+        assert!(guard.needs_reanchor(guard.max_error() / 2.0));
+        assert!(!guard.needs_reanchor(guard.max_error()));
+
+        guard.reanchor(exact);
+        assert_eq!(guard.value(), exact);
+        assert_eq!(guard.max_error(), 0.0);
+    }
+
+    #[test]
+    fn drift_guard_exact_test() {
+        let mut guard = DriftGuard::new(BigNumDec::from(5));
+
+        guard *= BigNumDec::from(7);
+        guard *= BigNumDec::from(1000);
+
+        assert_eq!(guard.value(), BigNumDec::from(35000));
+        assert_eq!(guard.max_error(), 0.0);
+    }
+
+    #[test]
+    fn tracked_test() {
+        use std::iter::repeat_n;
+
+        use crate::DEC_SIG_RANGE;
+
+        type BigNum = BigNumDec;
+
+        // These are the scenarios from `fuzzy_eq_failed1` and `fuzzy_eq_failed2`
+        let a = Tracked::new(BigNum::new(DEC_SIG_RANGE.1, 234));
+        let b = a + a + a + a + a;
+        let c = 2 * a + 3 * a;
+        let d: Tracked<_> = repeat_n(a, 20).sum();
+        let e = a * 20;
+
+        assert_ne!(b.value(), c.value());
+        assert_ne!(d.value(), e.value());
+        assert_eq!(b.ops(), 4);
+        assert_eq!(c.ops(), 3);
+        assert_eq!(d.ops(), 19);
+        assert_eq!(e.ops(), 1);
+        assert!(b.could_equal(c));
+        assert!(d.could_equal(e));
+
+        // Values that are actually different are still told apart
+        assert!(!b.could_equal(e));
+        assert!(!a.could_equal(a + a.value() / 1000));
+        assert!(a.could_equal(a));
+
+        let mut f = Tracked::from(BigNum::from(100));
+        f -= Tracked::new(BigNum::from(40));
+        f /= Tracked::new(BigNum::from(3));
+        f *= Tracked::new(BigNum::from(3));
+        f += Tracked::new(BigNum::from(1));
+        assert_eq!(f.value(), BigNum::from(61));
+        assert_eq!(f.ops(), 4);
+        assert_eq!((f - 1).ops(), 5);
+        assert_eq!((f / BigNum::from(2)).value(), BigNum::from(30));
+    }
+}
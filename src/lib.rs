@@ -1,18 +1,49 @@
 //! This crate defines a custom medium-precision number type. It can support any base `b`
-//! in the range `[0, 65535]`, and can approximately represent numbers up to
+//! in the range `[2, u32::MAX]`, and can approximately represent numbers up to
 //! `b ^ u64::MAX` (actually a bit higher than that but the math is complicated). A core
 //! goal for this type was that it can implement `Copy` and as a result it can be used in
 //! almost any context a normal unsigned integer would be valid.
 
+// The proc macros expand to `bignumbe_rs::...` paths, this lets them resolve inside the
+// crate's own tests
+#[cfg(all(feature = "macro", test))]
+extern crate self as bignumbe_rs;
+
 // public re-exporting
 #[cfg(feature = "macro")]
-pub use bignum_proc_macro::{create_efficient_base, make_bignum};
+pub use bignumbe_rs_macro::create_efficient_base;
+
+/// Creates either an efficient base with a type alias, or a value in a given base. See the
+/// `bignumbe-rs-macro` crate for details
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{make_bignum, BigNumBin, BigNumDec, Binary, Decimal};
+///
+/// const BIG: BigNumBin = make_bignum!(Binary, 1 << 63, 19);
+/// assert_eq!(BIG, BigNumBin::new(1, 82));
+///
+/// assert_eq!(make_bignum!(Decimal, 1.5e40), BigNumDec::new(15, 39));
+/// assert_eq!(make_bignum!(Decimal, 12345), BigNumDec::from(12345));
+///
+/// make_bignum!(5, B5Int);
+/// assert_eq!(B5Int::from(25) >> 2, B5Int::from(1));
+/// ```
+///
+/// For the built-in bases an out of range significand fails to compile
+/// ```compile_fail
+/// use bignumbe_rs::{make_bignum, BigNumBin, Binary};
+///
+/// const BAD: BigNumBin = make_bignum!(Binary, 1234, 19);
+/// ```
+#[cfg(feature = "macro")]
+pub use bignumbe_rs_macro::make_bignum;
 
 use std::{
     cmp::Ordering,
-    fmt::{Debug, Display},
+    fmt::{Debug, Display, LowerExp, LowerHex, UpperExp, UpperHex},
     iter::{Product, Sum},
-    ops::{Add, AddAssign, Div, Mul, MulAssign, Shl, Shr, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign},
 };
 
 use consts::{
@@ -24,10 +55,16 @@ use consts::{
 #[cfg(any(feature = "random", test))]
 pub mod random;
 
+#[cfg(any(feature = "proptest", test))]
+pub mod strategy;
+
 pub(crate) mod consts;
 pub(crate) mod macros;
 
+pub mod drift;
+pub mod signed;
 pub mod traits;
+pub mod utils;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// This represents the non-inclusive range of exponents that constitute a valid
@@ -103,6 +140,88 @@ impl SigRange {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The error type returned by fallible constructors and operations on `BigNumBase`
+pub enum BigNumError {
+    /// The significand isn't valid for the given exponent, i.e. the exponent is non-zero
+    /// and the significand is outside the base's `SigRange`
+    InvalidSignificand { sig: u64, exp: u64 },
+    /// The result would need an exponent larger than `u64::MAX`
+    ExpOverflow,
+    /// The right-hand side of a subtraction was larger than the left
+    SubUnderflow,
+    /// The product of two significands couldn't be normalized. This should be impossible
+    /// for a base that upholds the invariants listed in `Base`
+    MulNormalization,
+    /// A right shift would have shifted every digit out of a compact value
+    ShiftUnderflow,
+    /// The base is less than 2, which can't be used for any of the range math
+    InvalidBase(u32),
+    /// The right-hand side of a division was 0
+    DivisionByZero,
+    /// Normalizing the significand would have dropped a nonzero digit, changing the value
+    InexactNormalization { sig: u64, exp: u64 },
+}
+
+impl Display for BigNumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSignificand { sig, exp } => write!(
+                f,
+                "significand {} is not valid for a BigNum with exp {}",
+                sig, exp
+            ),
+            Self::ExpOverflow => write!(f, "exponent overflowed u64::MAX"),
+            Self::SubUnderflow => write!(f, "subtraction would be negative"),
+            Self::MulNormalization => write!(f, "product could not be normalized"),
+            Self::ShiftUnderflow => write!(f, "shift would remove every digit"),
+            Self::InvalidBase(number) => {
+                write!(f, "invalid base {}, bases must be at least 2", number)
+            }
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::InexactNormalization { sig, exp } => write!(
+                f,
+                "normalizing significand {} with exp {} would lose its last digit",
+                sig, exp
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BigNumError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The error type returned by `BigNumBase::from_str_radix`
+pub enum ParseError {
+    /// The input string was empty
+    Empty,
+    /// The input contained a character that isn't a digit in the radix
+    InvalidDigit(char),
+    /// The radix passed in isn't the number of the base being parsed into
+    RadixMismatch { radix: u32, number: u32 },
+    /// The radix is above 36, so there aren't enough letters to write its digits
+    UnsupportedRadix(u32),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse a BigNum from an empty string"),
+            Self::InvalidDigit(c) => write!(f, "invalid digit {:?} found in string", c),
+            Self::RadixMismatch { radix, number } => write!(
+                f,
+                "cannot parse a radix {} string into a BigNum with base {}",
+                radix, number
+            ),
+            Self::UnsupportedRadix(radix) => {
+                write!(f, "cannot parse digits in radix {}, the limit is 36", radix)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// If performance isn't critical I'd highly recommend the `create_default_base` macro
 /// which creates a base with sensible defaults. The only reason to create a custom
 /// implementation is if you find the default implementations' operations to be a
@@ -119,10 +238,10 @@ impl SigRange {
 /// - `B::pow(n) = NUMBER.pow(n)` for all `n < base.exp_range().max()`
 /// - `B::rshift(lhs, exp) = lhs / B::NUMBER.pow(n)` for all `n <= base.exp_range().max()`
 /// - `B::lshift(lhs, exp) = lhs * B::NUMBER.exp(n)` for all
-///     `n <= base.exp_range().max()`
+///   `n <= base.exp_range().max()`
 /// - `B::get_mag(n)` should return the highest exponent `x` such that `n >= B::pow(x)`,
-///     for all `n <= exp_range().max()`
-/// - `base.sig_range().min() * B::NUMBER > u64::MAX`
+///   for all `n <= exp_range().max()`
+/// - `base.sig_range().max() * B::NUMBER > u64::MAX`
 ///     - This restriction allows us to conveniently handle some construction cases
 ///
 /// The above requirements also hold for the `u128` versions of
@@ -155,7 +274,7 @@ impl SigRange {
 /// }
 ///
 /// impl Base for Base13{
-///     const NUMBER: u16 = 13;
+///     const NUMBER: u32 = 13;
 ///
 ///     fn new() -> Self {
 ///         let (exp_range, sig_range) = Self::calculate_ranges();
@@ -174,7 +293,7 @@ impl SigRange {
 pub trait Base: Copy + Debug {
     /// This contains the numeric value of the type. E.g. for binary 2, for decimal 10,
     /// etc.
-    const NUMBER: u16;
+    const NUMBER: u32;
 
     /// Function that can create an instance of this Base. Users should never have to
     /// manually create instances of this type. This is called implicitly on every
@@ -214,6 +333,16 @@ pub trait Base: Copy + Debug {
     /// to store the ranges in a const and return them directly in the `exp_range` and
     /// `sig_range` methods if convenient.
     fn calculate_ranges() -> (ExpRange, SigRange) {
+        Self::try_calculate_ranges().unwrap_or_else(|e| panic!("Unable to calculate ranges: {}", e))
+    }
+
+    /// Same as `calculate_ranges`, but returns `BigNumError::InvalidBase` instead of
+    /// panicking for bases below 2
+    fn try_calculate_ranges() -> Result<(ExpRange, SigRange), BigNumError> {
+        if Self::NUMBER < 2 {
+            return Err(BigNumError::InvalidBase(Self::NUMBER));
+        }
+
         if Self::NUMBER.is_power_of_two() && Self::NUMBER.ilog2().is_power_of_two() {
             // This is a special case where sig_max = u64::MAX. We have to handle it
             // specially to avoid overflowing the u64
@@ -221,13 +350,13 @@ pub trait Base: Copy + Debug {
             let exp = 64 / pow;
             let sig = Self::pow(exp - 1);
 
-            (ExpRange(exp - 1, exp), SigRange(sig, u64::MAX))
+            Ok((ExpRange(exp - 1, exp), SigRange(sig, u64::MAX)))
         } else {
             let exp = u64::MAX.ilog(Self::NUMBER as u64);
-            (
+            Ok((
                 ExpRange(exp - 1, exp),
                 SigRange(Self::pow(exp - 1), Self::pow(exp) - 1),
-            )
+            ))
         }
     }
 
@@ -263,13 +392,25 @@ pub trait Base: Copy + Debug {
     }
 
     /// This is a function that computes the highest power `x` such that
-    /// `sig >= (Self::NUMBER ^ x)`. There is a default implementation that uses `ilog`,
-    /// and it is recommended to use this unless there is a special way to find the
-    /// magnitude (e.g. binary and decimal have specialized `ilog` implementations).
-    /// As a special case, bases that are powers of 2 or 10 can use log arithmetic to
-    /// convert. I tried this with octal and hexadecimal but it had no noticeable impact.
+    /// `sig >= (Self::NUMBER ^ x)`. The default implementation binary searches the table
+    /// from `powers` if there is one, and otherwise uses `ilog`, which loops for bases
+    /// that aren't powers of 2 or 10. It is recommended to use this unless there is a
+    /// special way to find the magnitude (e.g. binary and decimal have specialized `ilog`
+    /// implementations). As a special case, bases that are powers of 2 or 10 can use log
+    /// arithmetic to convert. I tried this with octal and hexadecimal but it had no
+    /// noticeable impact.
     fn get_mag(sig: u64) -> u32 {
-        sig.ilog(Self::NUMBER as u64)
+        match Self::powers() {
+            Some(powers) => utils::mag_from_powers(sig, powers),
+            None => sig.ilog(Self::NUMBER as u64),
+        }
+    }
+
+    /// Returns a table of every power of `Self::NUMBER` that fits in a `u64`, starting
+    /// with `Self::NUMBER ^ 0 = 1`, if the implementer has one. The default `get_mag` uses
+    /// it to avoid a slow `ilog`. The default implementation returns `None`
+    fn powers() -> Option<&'static [u64]> {
+        None
     }
 
     /// This is a function that computes the same thing as `get_mag` but in a u128 value.
@@ -281,9 +422,49 @@ pub trait Base: Copy + Debug {
 
     /// This method just fetches `Self::NUMBER` but is provided as an instance method for
     /// convenience. Overriding it is undefined behavior
-    fn as_number(&self) -> u16 {
+    fn as_number(&self) -> u32 {
         Self::NUMBER
     }
+
+    /// Checks the range invariants listed in the trait docs, panicking if any of them
+    /// don't hold. This is called by `BigNumBase::new` in debug builds so a misconfigured
+    /// base is caught early, and does nothing in release builds. There should be no reason
+    /// to override it
+    fn debug_assert_invariants() {
+        let base = Self::new();
+        let ExpRange(min_exp, max_exp) = base.exp_range();
+        let SigRange(min_sig, max_sig) = base.sig_range();
+
+        debug_assert!(
+            min_exp > 0 && max_exp == min_exp + 1,
+            "Invalid exp_range for base {}: {:?}",
+            Self::NUMBER,
+            base.exp_range()
+        );
+        debug_assert!(
+            min_sig as u128 == Self::pow_u128(min_exp),
+            "Invalid min_sig for base {}: {} != {}^{}",
+            Self::NUMBER,
+            min_sig,
+            Self::NUMBER,
+            min_exp
+        );
+        debug_assert!(
+            max_sig as u128 == Self::pow_u128(max_exp) - 1,
+            "Invalid max_sig for base {}: {} != {}^{} - 1",
+            Self::NUMBER,
+            max_sig,
+            Self::NUMBER,
+            max_exp
+        );
+        debug_assert!(
+            max_sig as u128 * Self::NUMBER as u128 > u64::MAX as u128,
+            "Invalid max_sig for base {}: {} * {} <= u64::MAX",
+            Self::NUMBER,
+            max_sig,
+            Self::NUMBER
+        );
+    }
 }
 
 /// This type represents a binary base. It contains more efficient overrides of the
@@ -310,8 +491,17 @@ pub type BigNumHex = BigNumBase<Hexadecimal>;
 pub struct Decimal;
 pub type BigNumDec = BigNumBase<Decimal>;
 
+/// This type represents an arbitrary base `B`, for when defining a named base with
+/// `create_default_base!` is more than you need. The ranges are computed at compile time,
+/// but it uses the default `Base` implementations for everything else, so the predefined
+/// bases should be preferred where possible. E.g. `BigNumConst::<37>::from(5)`. Using a
+/// base less than 2 fails to compile
+#[derive(Clone, Copy, Debug)]
+pub struct ConstBase<const B: u32>;
+pub type BigNumConst<const B: u32> = BigNumBase<ConstBase<B>>;
+
 impl Base for Binary {
-    const NUMBER: u16 = 2;
+    const NUMBER: u32 = 2;
     fn new() -> Self {
         Self
     }
@@ -360,7 +550,7 @@ impl Base for Binary {
 }
 
 impl Base for Octal {
-    const NUMBER: u16 = 8;
+    const NUMBER: u32 = 8;
 
     fn new() -> Self {
         Self
@@ -400,7 +590,7 @@ impl Base for Octal {
 }
 
 impl Base for Hexadecimal {
-    const NUMBER: u16 = 16;
+    const NUMBER: u32 = 16;
 
     fn new() -> Self {
         Self
@@ -440,7 +630,7 @@ impl Base for Hexadecimal {
 }
 
 impl Base for Decimal {
-    const NUMBER: u16 = 10;
+    const NUMBER: u32 = 10;
 
     fn new() -> Self {
         Self
@@ -471,6 +661,43 @@ impl Base for Decimal {
     }
 }
 
+impl<const B: u32> ConstBase<B> {
+    const RANGES: (ExpRange, SigRange) = {
+        assert!(B >= 2, "Invalid base, bases must be at least 2");
+
+        // Same as `Base::calculate_ranges`, which can't be called in a const context
+        if B.is_power_of_two() && B.ilog2().is_power_of_two() {
+            let exp = 64 / B.ilog2();
+            (
+                ExpRange(exp - 1, exp),
+                SigRange((B as u64).pow(exp - 1), u64::MAX),
+            )
+        } else {
+            let exp = u64::MAX.ilog(B as u64);
+            (
+                ExpRange(exp - 1, exp),
+                SigRange((B as u64).pow(exp - 1), (B as u64).pow(exp) - 1),
+            )
+        }
+    };
+}
+
+impl<const B: u32> Base for ConstBase<B> {
+    const NUMBER: u32 = B;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn exp_range(&self) -> ExpRange {
+        Self::RANGES.0
+    }
+
+    fn sig_range(&self) -> SigRange {
+        Self::RANGES.1
+    }
+}
+
 /// This is the main struct for `bignumbe-rs`.
 ///
 /// It takes a generic argument for the base, e.g.
@@ -496,7 +723,7 @@ impl Base for Decimal {
 /// assert_eq!(bn1 * bn2, bn2);
 /// assert_eq!(bn2 * bn2, BigNum::new(u64::MAX - 1, 64));
 /// ```
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub struct BigNumBase<T>
 where
     T: Base,
@@ -515,56 +742,73 @@ where
     /// `12341234 * 2^12341`. This method will perform normalization if necessary, to
     /// ensure the significand is in the valid range (if the number is non-compact). As
     /// such when creating a BigNum from scratch you should always use this unless you
-    /// absolutely need a raw constructor
+    /// absolutely need a raw constructor. A significand of 0 always gives 0, whatever the
+    /// exponent
     pub fn new(sig: u64, exp: u64) -> Self {
+        Self::checked_new(sig, exp).unwrap_or_else(|e| {
+            panic!(
+                "Unable to create a BigNum from {} * {}^{}: {}",
+                sig,
+                T::NUMBER,
+                exp,
+                e
+            )
+        })
+    }
+
+    /// Works like `new`, but returns an error instead of panicking. The only input that
+    /// can't be normalized is a significand above `max_sig` with an exponent of
+    /// `u64::MAX`, which gives `BigNumError::ExpOverflow`
+    pub fn checked_new(sig: u64, exp: u64) -> Result<Self, BigNumError> {
+        T::debug_assert_invariants();
         let base = T::new();
 
+        if sig == 0 {
+            return Ok(Self { sig, exp: 0, base });
+        }
+
         let SigRange(min_sig, max_sig) = base.sig_range();
         let ExpRange(min_exp, _) = base.exp_range();
 
         if sig >= min_sig && sig <= max_sig {
-            Self { sig, exp, base }
+            Ok(Self { sig, exp, base })
         } else if sig > max_sig {
             // Since we know `max_sig * base.as_number() > u64::MAX`, we also know
             // that `sig / base.as_number() <= max_sig`
-            Self {
+            Ok(Self {
                 sig: T::rshift(sig, 1),
-                exp: exp.checked_add(1).unwrap_or_else(|| {
-                    panic!(
-                        "Unable to create a BigNum with an exp of u64::MAX and a significand greater than max_sig = {}",
-                        max_sig
-                    )
-                }),
+                exp: exp.checked_add(1).ok_or(BigNumError::ExpOverflow)?,
                 base,
-            }
+            })
         } else if exp == 0 {
-            Self { sig, exp, base }
-        } else if sig == 0 {
-            panic!(
-                "Unable to create BigNumBase with exp of {} and sig of 0",
-                exp
-            );
+            Ok(Self { sig, exp, base })
         } else {
             let mag = T::get_mag(sig);
 
-            if mag.saturating_add(exp as u32) <= min_exp {
-                Self {
+            if (mag as u64).saturating_add(exp) <= min_exp as u64 {
+                Ok(Self {
                     sig: T::lshift(sig, exp as u32),
                     exp: 0,
                     base,
-                }
+                })
             } else {
                 let adj = min_exp - mag;
 
-                Self {
+                Ok(Self {
                     sig: T::lshift(sig, adj),
                     exp: exp - adj as u64,
                     base,
-                }
+                })
             }
         }
     }
 
+    /// Works like `new`, but if normalizing would push the exponent past `u64::MAX` this
+    /// returns `max_value()` instead of panicking
+    pub fn new_saturating(sig: u64, exp: u64) -> Self {
+        Self::checked_new(sig, exp).unwrap_or_else(|_| Self::max_value())
+    }
+
     /// Creates a BigNumBase directly from values, panicking if not possible. This is
     /// mostly for testing but may be more performant on inputs that are guaranteed valid
     pub fn new_raw(sig: u64, exp: u64) -> Self {
@@ -589,11 +833,157 @@ max_sig:
         }
     }
 
+    /// Creates a `BigNumBase` instance that represents exactly `T::NUMBER^exp`. E.g.
+    /// `BigNumDec::power_of_base(3)` is `1000`, and `BigNumBin::power_of_base(100)` is
+    /// `2^100`
+    pub fn power_of_base(exp: u64) -> Self {
+        let base = T::new();
+        let ExpRange(min_exp, _) = base.exp_range();
+
+        if exp <= min_exp as u64 {
+            Self {
+                sig: T::pow(exp as u32),
+                exp: 0,
+                base,
+            }
+        } else {
+            Self {
+                sig: base.sig_range().min(),
+                exp: exp - min_exp as u64,
+                base,
+            }
+        }
+    }
+
+    /// Returns the order of magnitude of this number in its base, i.e. the highest `x`
+    /// such that `self >= T::NUMBER^x`. As a special case a value of 0 has a magnitude
+    /// of 0. Since the magnitude of the largest values doesn't fit in a `u64` this
+    /// saturates at `u64::MAX`
+    pub fn magnitude(self) -> u64 {
+        if self.exp == 0 {
+            if self.sig == 0 {
+                0
+            } else {
+                T::get_mag(self.sig) as u64
+            }
+        } else {
+            self.exp.saturating_add(self.base.exp_range().min() as u64)
+        }
+    }
+
+    /// Returns the largest value representable in this base, `max_sig * T::NUMBER^u64::MAX`
+    pub fn max_value() -> Self {
+        let base = T::new();
+
+        Self {
+            sig: base.sig_range().max(),
+            exp: u64::MAX,
+            base,
+        }
+    }
+
+    /// Returns the smallest value representable in this base, which is 0
+    pub fn min_value() -> Self {
+        Self {
+            sig: 0,
+            exp: 0,
+            base: T::new(),
+        }
+    }
+
+    /// Returns the smallest positive value, which is 1 in every base
+    pub fn smallest_positive() -> Self {
+        Self::from(1)
+    }
+
+    /// Returns the value of one unit in the last place of the significand, i.e. the
+    /// smallest amount that can be added to this value without being lost. This is 1 for
+    /// compact values and `T::NUMBER^exp` otherwise
+    pub fn ulp(self) -> Self {
+        Self::power_of_base(self.exp)
+    }
+
     /// Returns true if the values are valid for the current base
     fn is_valid(sig: u64, exp: u64, range: SigRange) -> bool {
         sig <= range.max() && (exp == 0 || sig >= range.min())
     }
 
+    /// Returns true if `(sig, exp)` is already in canonical form, i.e. the significand is
+    /// in the valid range for a non-compact number. Values built with `new` always are,
+    /// but writing to the public fields directly can break this
+    pub fn is_normalized(self) -> bool {
+        Self::is_valid(self.sig, self.exp, self.base.sig_range())
+    }
+
+    /// Returns the `(sig, exp)` pair backing this value
+    pub fn into_parts(self) -> (u64, u64) {
+        (self.sig, self.exp)
+    }
+
+    /// Works like `checked_new`, but also returns `BigNumError::InexactNormalization` if
+    /// normalizing would change the value. Normalizing only loses information when the
+    /// significand is above `max_sig` and has to be shifted down a digit, so e.g. for
+    /// `BigNumDec`, `(10^19, 5)` is fine but `(10^19 + 1, 5)` errors. Significands below
+    /// the range are shifted up, which is always exact
+    pub fn try_from_parts_exact(sig: u64, exp: u64) -> Result<Self, BigNumError> {
+        let res = Self::checked_new(sig, exp)?;
+
+        if sig > T::new().sig_range().max() && T::lshift_u128(res.sig as u128, 1) != sig as u128 {
+            Err(BigNumError::InexactNormalization { sig, exp })
+        } else {
+            Ok(res)
+        }
+    }
+
+    /// Creates a value directly from a `(sig, exp)` pair, returning an error instead of
+    /// normalizing if the significand isn't in the valid range. This is the checked
+    /// alternative to setting the public fields yourself, and round-trips `into_parts`
+    pub fn from_parts(sig: u64, exp: u64) -> Result<Self, BigNumError> {
+        let base = T::new();
+
+        if Self::is_valid(sig, exp, base.sig_range()) {
+            Ok(Self { sig, exp, base })
+        } else {
+            Err(BigNumError::InvalidSignificand { sig, exp })
+        }
+    }
+
+    /// Canonicalizes a value whose fields have been modified directly, applying the same
+    /// adjustment `new` would. Panics in the same cases `new` does
+    pub fn normalize(self) -> Self {
+        Self::new(self.sig, self.exp)
+    }
+
+    /// Expresses the value with an exponent of `new_exp`, shifting the significand to
+    /// match. Raising the exponent drops the digits shifted out of the significand (all of
+    /// them if the exponent goes up by at least `max_exp`), while lowering it saturates
+    /// the significand at `max_sig` if the value can't be represented there. This is meant
+    /// for lining values up before operating on their significands directly, so the result
+    /// is usually not normalized and should be passed through `normalize` before being
+    /// used in arithmetic. Note that the significand can end up as 0
+    pub fn rescale(self, new_exp: u64) -> Self {
+        let SigRange(_, max_sig) = self.base.sig_range();
+        let ExpRange(_, max_exp) = self.base.exp_range();
+
+        let sig = if new_exp >= self.exp {
+            match new_exp - self.exp {
+                shift if shift >= max_exp as u64 => 0,
+                shift => T::rshift(self.sig, shift as u32),
+            }
+        } else {
+            match self.exp - new_exp {
+                shift if shift > max_exp as u64 => max_sig,
+                shift => T::lshift_u128(self.sig as u128, shift as u32).min(max_sig as u128) as u64,
+            }
+        };
+
+        Self {
+            sig,
+            exp: new_exp,
+            base: self.base,
+        }
+    }
+
     /// Allows fuzzy comparison between two values. Since operations can result in loss of
     /// precision this allows you to compare values that may have drifted. Since each
     /// operation can result in an error of 1, an upper bound is the sum of the number of
@@ -616,101 +1006,66 @@ max_sig:
             false
         }
     }
-}
 
-impl<T> PartialEq for BigNumBase<T>
-where
-    T: Base,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.sig == other.sig && self.exp == other.exp
-    }
-}
+    /// Returns `|self - other| / max(self, other)` as an `f64`, for when a measure of how
+    /// far apart two values are is more useful than the yes/no of `fuzzy_eq`. This is
+    /// `0.0` for equal values (including two zeroes) and `1.0` when one of them is zero.
+    /// The difference is computed exactly in `u128` space so a difference of 1 in the
+    /// significand gives an error of about `1 / sig`. Values whose exponents are too far
+    /// apart for that differ by more than an `f64` can tell from 1, so they return `1.0`
+    pub fn relative_error(self, other: Self) -> f64 {
+        let ExpRange(_, max_exp) = self.base.exp_range();
 
-impl<T> Eq for BigNumBase<T> where T: Base {}
+        let (min, max) = if self > other {
+            (other, self)
+        } else {
+            (self, other)
+        };
 
-impl<T> Ord for BigNumBase<T>
-where
-    T: Base,
-{
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self.exp.cmp(&other.exp) {
-            Ordering::Less => Ordering::Less,
-            Ordering::Greater => Ordering::Greater,
-            Ordering::Equal => match self.sig.cmp(&other.sig) {
-                Ordering::Less => Ordering::Less,
-                Ordering::Greater => Ordering::Greater,
-                Ordering::Equal => Ordering::Equal,
-            },
+        if max.sig == 0 {
+            return 0.0;
         }
-    }
-}
-
-impl<T> PartialOrd for BigNumBase<T>
-where
-    T: Base,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl<T> Add for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        let base = self.base;
-        let SigRange(min_sig, max_sig) = base.sig_range();
-        let ExpRange(_, max_exp) = base.exp_range();
 
-        let (max, min) = if self > rhs { (self, rhs) } else { (rhs, self) };
         let shift = max.exp - min.exp;
-
-        if shift >= max_exp as u64 {
-            // This shift is guaranteed to result in 0 on lhs, no need to compute
-            return max;
+        if shift > max_exp as u64 {
+            return 1.0;
         }
 
-        let result = max.sig.wrapping_add(T::rshift(min.sig, shift as u32));
-
-        let (sig, exp) = if result < max.sig {
-            // How much we need to add to the overflow result to make up for differences
-            // in the significand's range
-            let diff = u64::MAX - max_sig;
-            (min_sig + T::rshift(result + diff, 1), max.exp + 1)
-        } else if T::NUMBER != 2 && result > max_sig {
-            (T::rshift(result, 1), max.exp + 1)
-        } else {
-            (result, max.exp)
-        };
+        let max_sig = T::lshift_u128(max.sig as u128, shift as u32);
 
-        Self {
-            sig,
-            exp,
-            base: self.base,
-        }
+        (max_sig - min.sig as u128) as f64 / max_sig as f64
     }
-}
 
-impl<T> AddAssign for BigNumBase<T>
-where
-    T: Base,
-{
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    /// Wraps an iterator, yielding the maximum value seen so far at each step. E.g. the
+    /// values `[1, 3, 2, 5]` would yield `[1, 3, 3, 5]`. Useful for tracking record highs
+    pub fn running_max<I: Iterator<Item = Self>>(iter: I) -> impl Iterator<Item = Self> {
+        iter.scan(None, |max: &mut Option<Self>, n| {
+            let curr = match *max {
+                Some(m) if m >= n => m,
+                _ => n,
+            };
+            *max = Some(curr);
+
+            Some(curr)
+        })
     }
-}
 
-impl<T> Sub for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = Self;
+    /// Returns true if `self` exceeds `other` by more than `orders` orders of magnitude,
+    /// meaning `other` is negligible in comparison. E.g. for `BigNumDec`, `10^20` dwarfs
+    /// `10^5` with `orders = 14` but not with `orders = 15`. Any non-zero value dwarfs 0
+    pub fn dwarfs(self, other: Self, orders: u64) -> bool {
+        if other.sig == 0 {
+            return self.sig != 0;
+        }
 
-    fn sub(self, rhs: Self) -> Self::Output {
+        self.magnitude()
+            .checked_sub(other.magnitude())
+            .is_some_and(|diff| diff > orders)
+    }
+
+    /// Subtracts `rhs` from `self`, returning `BigNumError::SubUnderflow` if `rhs` is
+    /// larger. The `Sub` implementation panics in this case
+    pub fn try_sub(self, rhs: Self) -> Result<Self, BigNumError> {
         let base = self.base;
         let SigRange(min_sig, _) = base.sig_range();
         let ExpRange(min_exp, max_exp) = base.exp_range();
@@ -718,19 +1073,14 @@ where
         let (max, min) = if self >= rhs {
             (self, rhs)
         } else {
-            panic!(
-                "Attempt to subtract 
-{:?} from 
-{:?}",
-                rhs, self
-            )
+            return Err(BigNumError::SubUnderflow);
         };
 
         let shift = max.exp - min.exp;
 
         if shift >= max_exp as u64 {
             // This shift is guaranteed to result in 0 on rhs, no need to compute
-            return max;
+            return Ok(max);
         }
 
         let result = max.sig.wrapping_sub(T::rshift(min.sig, shift as u32));
@@ -742,7 +1092,7 @@ where
             (result, max.exp)
         };
 
-        if res_sig == 0 {
+        Ok(if res_sig == 0 {
             Self {
                 sig: 0,
                 exp: 0,
@@ -756,24 +1106,16 @@ where
             }
         } else {
             // This operation can result in arbitrary loss in magnitude so we have to
-            // calculate the differential directly
+            // calculate the differential directly. Shifting `res_sig` left by `adj` puts it
+            // in `[min_sig, max_sig]`, which we can only do while `res_exp` can absorb it
             let mag = T::get_mag(res_sig);
             let adj = min_exp - mag;
 
-            if adj as u64 == res_exp {
-                Self {
-                    sig: T::lshift(res_sig, adj),
-                    exp: 0,
-                    base,
-                }
-            } else if adj as u64 >= res_exp {
-                // Have to adjust by more than exp so we will have a compact result
-                // TODO Verify this again, pretty sure it's right but I can't figure out
-                // why the -1 is there
-                let diff = adj as u64 - res_exp - 1;
-
+            if adj as u64 >= res_exp {
+                // The value is `res_sig * N^res_exp`, and `res_sig * N^adj <= max_sig`, so
+                // shifting by `res_exp` gives the exact compact value
                 Self {
-                    sig: T::lshift(res_sig, diff as u32),
+                    sig: T::lshift(res_sig, res_exp as u32),
                     exp: 0,
                     base,
                 }
@@ -784,44 +1126,99 @@ where
                     base,
                 }
             }
-        }
+        })
     }
-}
 
-impl<T> SubAssign for BigNumBase<T>
-where
-    T: Base,
-{
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    /// Divides two values, returning `None` if `rhs` is 0. The `Div` implementation panics
+    /// in this case
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.try_div(rhs).ok()
     }
-}
 
-impl<T> Mul for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = BigNumBase<T>;
+    /// Divides two values, returning `BigNumError::DivisionByZero` if `rhs` is 0
+    pub fn try_div(self, rhs: Self) -> Result<Self, BigNumError> {
+        if rhs.sig == 0 {
+            return Err(BigNumError::DivisionByZero);
+        }
 
-    fn mul(self, rhs: Self) -> Self::Output {
+        match self.cmp(&rhs) {
+            Ordering::Less => return Ok(Self::new(0, 0)),
+            Ordering::Equal => return Ok(Self::new(1, 0)),
+            _ => (),
+        }
+
+        if self.exp == 0 {
+            return Ok(Self {
+                sig: self.sig / rhs.sig,
+                ..self
+            });
+        }
+
+        let base = self.base;
+        let ExpRange(min_exp, max_exp) = base.exp_range();
+
+        // The basic idea here is to project both numbers to a u128 like in multiplication,
+        // but this time the lhs goes in the upper 64 bits and the rhs goes in the lower.
+        // This way we preserve as much info as possible
+        let (lsig, rsig) = (T::lshift_u128(self.sig as u128, max_exp), rhs.sig as u128);
+        let (lexp, rexp) = (self.exp, rhs.exp);
+
+        let res_sig = lsig / rsig;
+        let res_exp = lexp - rexp;
+
+        let mag = T::get_mag_u128(res_sig);
+        // lsig had a magnitude of min_exp + max_exp, this tracks how many orders of
+        // magnitude were "lost" with this division
+        let adj = (min_exp + max_exp) - mag;
+
+        Ok(if adj as u64 <= res_exp {
+            // We would shift by max_exp normally, but since we lost adj orders of
+            // magnitude we have to shift by max_exp - adj
+            Self {
+                sig: T::rshift_u128(res_sig, max_exp - adj) as u64,
+                exp: res_exp - adj as u64,
+                ..self
+            }
+        } else {
+            let diff = adj as u64 - res_exp;
+            // We would normally shift by max_exp, but we lost adj order of magnitude
+            // and took diff orders of magnitude from the exponent, so we shift by
+            // max_exp - adj + diff
+            Self {
+                sig: T::rshift_u128(res_sig, max_exp - adj + diff as u32) as u64,
+                exp: 0,
+                ..self
+            }
+        })
+    }
+
+    /// Multiplies two values, returning `None` if the exponent of the result would
+    /// overflow a `u64`. The `Mul` implementation panics in this case
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.try_mul(rhs).ok()
+    }
+
+    /// Multiplies two values, returning `BigNumError::ExpOverflow` if the exponent of the
+    /// result would overflow a `u64`
+    pub fn try_mul(self, rhs: Self) -> Result<Self, BigNumError> {
         let base = self.base;
 
         if self.exp == 0 && self.sig == 1 {
-            return rhs;
+            return Ok(rhs);
         } else if self.exp == 0 && self.sig == 0 {
-            return Self {
+            return Ok(Self {
                 sig: 0,
                 exp: 0,
                 base,
-            };
+            });
         } else if rhs.exp == 0 && rhs.sig == 1 {
-            return self;
+            return Ok(self);
         } else if rhs.exp == 0 && rhs.sig == 0 {
-            return Self {
+            return Ok(Self {
                 sig: 0,
                 exp: 0,
                 base,
-            };
+            });
         }
 
         let (lsig, rsig) = (self.sig as u128, rhs.sig as u128);
@@ -830,7 +1227,7 @@ where
         let ExpRange(min_exp, _) = base.exp_range();
 
         let res_sig = lsig * rsig;
-        let res_exp = lexp + rexp;
+        let res_exp = lexp.checked_add(rexp).ok_or(BigNumError::ExpOverflow)?;
 
         if res_sig > max_sig as u128 {
             let mag = T::get_mag_u128(res_sig);
@@ -838,710 +1235,4880 @@ where
             let adj = mag - min_exp;
             let sig = T::rshift_u128(res_sig, adj);
             if sig > u64::MAX as u128 {
-                panic!(
-                    "Unable to normalize result for multiplication between {:?} and {:?}",
-                    self, rhs
-                );
+                Err(BigNumError::MulNormalization)
             } else {
-                Self {
+                Ok(Self {
                     sig: sig as u64,
-                    exp: res_exp + adj as u64,
+                    exp: res_exp
+                        .checked_add(adj as u64)
+                        .ok_or(BigNumError::ExpOverflow)?,
                     base,
-                }
+                })
             }
         } else if res_exp != 0 && res_sig < min_sig as u128 {
-            panic!(
-                "Found invalid significand while multiplying {:?} and {:?}",
-                self, rhs
-            );
+            Err(BigNumError::MulNormalization)
         } else {
-            Self {
+            Ok(Self {
                 sig: res_sig as u64,
                 exp: res_exp,
                 base,
-            }
+            })
         }
     }
-}
 
-impl<T> MulAssign for BigNumBase<T>
-where
-    T: Base,
-{
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs;
+    /// Raises this value to the power `n` using exponentiation by squaring. Each
+    /// multiplication can drift by 1 so expect up to `2 * log2(n)` of error. Panics if
+    /// the exponent of the result would overflow a `u64`
+    pub fn pow(self, n: u32) -> Self {
+        self.checked_pow(n).unwrap_or_else(|| {
+            panic!(
+                "Exponent overflow while raising {:?} to the power {}",
+                self, n
+            )
+        })
     }
-}
 
-impl<T> Div for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = Self;
+    /// Same as `pow` but returns `None` if the exponent of the result would overflow a
+    /// `u64`. This stops as soon as an intermediate value overflows, so it's cheap even
+    /// for huge `n`
+    pub fn checked_pow(self, n: u32) -> Option<Self> {
+        self.checked_pow_u64(n as u64)
+    }
 
-    // The basic idea here is to project both numbers to a u128 like in multiplication,
-    // but this time the lhs goes in the upper 64 bits and the rhs goes in the lower. This
-    // way we preserve as much info as possible
-    fn div(self, rhs: Self) -> Self::Output {
-        match self.cmp(&rhs) {
-            Ordering::Less => return Self::new(0, 0),
-            Ordering::Equal => return Self::new(1, 0),
-            _ => (),
+    /// `checked_pow` with a `u64` exponent, for callers like `geometric_sum` whose
+    /// exponent isn't limited to a `u32`
+    fn checked_pow_u64(self, n: u64) -> Option<Self> {
+        let mut res = Self::from(1);
+        let mut acc = self;
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                res = res.checked_mul(acc)?;
+            }
+
+            n >>= 1;
+
+            if n > 0 {
+                acc = acc.checked_mul(acc)?;
+            }
+        }
+
+        Some(res)
+    }
+
+    /// Same as `pow` but instead of panicking on overflow it returns the largest
+    /// representable value along with `true`. The second element is `false` if the
+    /// result fit
+    pub fn overflowing_pow(self, n: u32) -> (Self, bool) {
+        match self.checked_pow(n) {
+            Some(res) => (res, false),
+            None => (Self::max_value(), true),
         }
+    }
+
+    /// Raises this value to the power `n` in place, see `pow`. Panics if the exponent of
+    /// the result would overflow a `u64`
+    pub fn pow_assign(&mut self, n: u32) {
+        *self = self.pow(n);
+    }
 
+    /// Returns the square root of this value, rounded down. Compact values are exact. For
+    /// expanded values the significand is widened to a `u128` first so the root keeps a
+    /// full significand's worth of digits
+    pub fn isqrt(self) -> Self {
         if self.exp == 0 {
             return Self {
-                sig: self.sig / rhs.sig,
+                sig: self.sig.isqrt(),
                 ..self
             };
         }
 
-        let base = self.base;
-        let ExpRange(min_exp, max_exp) = base.exp_range();
-
-        let (lsig, rsig) = (T::lshift_u128(self.sig as u128, max_exp), rhs.sig as u128);
-        let (lexp, rexp) = (self.exp, rhs.exp);
+        let ExpRange(_, max_exp) = self.base.exp_range();
+        // `N^max_exp <= 2^64`, so shifting by at most `max_exp` can't overflow a `u128`.
+        // The remaining exponent has to be even to be halved
+        let mut shift = self.exp.min(max_exp as u64);
+        if (self.exp - shift) % 2 == 1 {
+            shift -= 1;
+        }
 
-        let res_sig = lsig / rsig;
-        let res_exp = lexp - rexp;
+        let wide = T::lshift_u128(self.sig as u128, shift as u32);
+        Self::from_wide(wide.isqrt(), (self.exp - shift) / 2)
+    }
 
-        let mag = T::get_mag_u128(res_sig);
-        // lsig had a magnitude of min_exp + max_exp, this tracks how many orders of
-        // magnitude were "lost" with this division
-        let adj = (min_exp + max_exp) - mag;
+    /// Replaces this value with its square root, rounded down, see `isqrt`
+    pub fn isqrt_assign(&mut self) {
+        *self = self.isqrt();
+    }
 
-        if adj as u64 <= res_exp {
-            // We would shift by max_exp normally, but since we lost adj orders of
-            // magnitude we have to shift by max_exp - adj
-            Self {
-                sig: T::rshift_u128(res_sig, max_exp - adj) as u64,
-                exp: res_exp - adj as u64,
-                ..self
-            }
-        } else {
-            let diff = adj as u64 - res_exp;
-            // We would normally shift by max_exp, but we lost adj order of magnitude
-            // and took diff orders of magnitude from the exponent, so we shift by
-            // max_exp - adj + diff
-            Self {
-                sig: T::rshift_u128(res_sig, max_exp - adj + diff as u32) as u64,
-                exp: 0,
-                ..self
-            }
+    /// Splits this value into prestige layers, where each layer spans
+    /// `layer_size_orders` orders of magnitude. Returns the index of the layer this value
+    /// falls in along with the residual within that layer, i.e.
+    /// `self / T::NUMBER^(layer * layer_size_orders)` rounded down. So
+    /// `residual << (layer * layer_size_orders)` gives back `self` minus whatever digits
+    /// were truncated. Panics if `layer_size_orders` is 0
+    pub fn prestige_layer(&self, layer_size_orders: u64) -> (u64, Self) {
+        if layer_size_orders == 0 {
+            panic!("Unable to split a value into layers of 0 orders of magnitude");
         }
+
+        let layer = self.magnitude() / layer_size_orders;
+
+        (layer, *self >> (layer * layer_size_orders))
     }
-}
 
-impl<T> Shl<u64> for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = Self;
+    /// Computes the `n`th term of the linear recurrence
+    /// `a_k = coeffs[0] * a_(k-1) + coeffs[1] * a_(k-2)`, where `seed = [a_0, a_1]`. E.g.
+    /// the Fibonacci sequence is `seed = [0, 1]` with `coeffs = [1, 1]`. This uses
+    /// matrix exponentiation so it takes `O(log n)` multiplications rather than `O(n)`,
+    /// which also means less drift than computing the terms one at a time
+    pub fn recurrence_cost(n: u64, seed: [Self; 2], coeffs: [u64; 2]) -> Self {
+        type Matrix<T> = [[BigNumBase<T>; 2]; 2];
 
-    fn shl(self, rhs: u64) -> Self::Output {
-        let ExpRange(min_exp, _) = self.base.exp_range();
+        fn mat_mul<T: Base>(a: Matrix<T>, b: Matrix<T>) -> Matrix<T> {
+            let cell = |i: usize, j: usize| a[i][0] * b[0][j] + a[i][1] * b[1][j];
 
-        if self.exp != 0 {
-            // Already in expanded form
-            Self {
-                exp: self.exp.checked_add(rhs).unwrap(),
-                ..self
+            [[cell(0, 0), cell(0, 1)], [cell(1, 0), cell(1, 1)]]
+        }
+
+        if n == 0 {
+            return seed[0];
+        }
+
+        let (zero, one) = (Self::from(0), Self::from(1));
+        let mut res: Matrix<T> = [[one, zero], [zero, one]];
+        let mut acc: Matrix<T> = [[Self::from(coeffs[0]), Self::from(coeffs[1])], [one, zero]];
+        let mut k = n - 1;
+
+        while k > 0 {
+            if k & 1 == 1 {
+                res = mat_mul(res, acc);
             }
-        } else {
-            let mag = T::get_mag(self.sig);
-            // The number of orders of magnitude the significand can be increased
-            let adj = min_exp - mag;
 
-            if adj as u64 > rhs {
-                // The result can be made compact
-                Self {
-                    sig: T::lshift(self.sig, rhs as u32),
-                    exp: 0,
-                    ..self
-                }
-            } else {
-                Self {
-                    sig: T::lshift(self.sig, adj),
-                    exp: rhs - adj as u64,
-                    ..self
-                }
+            k >>= 1;
+
+            if k > 0 {
+                acc = mat_mul(acc, acc);
             }
         }
+
+        res[0][0] * seed[1] + res[0][1] * seed[0]
     }
-}
 
-impl<T> Shr<u64> for BigNumBase<T>
-where
-    T: Base,
-{
-    type Output = Self;
+    /// Encodes this value as 16 bytes, the significand followed by the exponent, both
+    /// little-endian. The base isn't stored so it must be known when decoding
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
 
-    fn shr(self, rhs: u64) -> Self::Output {
-        if self.exp >= rhs {
-            return Self {
-                exp: self.exp - rhs,
-                ..self
-            };
-        }
+        bytes[..8].copy_from_slice(&self.sig.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.exp.to_le_bytes());
 
-        let mag = T::get_mag(self.sig);
-        let diff = rhs - self.exp;
+        bytes
+    }
 
-        if diff > mag as u64 {
-            panic!("Unable to shift {:?} by {}", self, rhs);
-        }
+    /// Decodes a value written by `to_le_bytes`. Returns `None` if the bytes don't
+    /// describe a valid value for this base, using the same rules as `new_raw`
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Option<Self> {
+        let base = T::new();
+        let sig = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let exp = u64::from_le_bytes(bytes[8..].try_into().unwrap());
 
-        Self {
-            sig: T::rshift(self.sig, diff as u32),
-            exp: 0,
-            ..self
+        if Self::is_valid(sig, exp, base.sig_range()) {
+            Some(Self { sig, exp, base })
+        } else {
+            None
         }
     }
-}
 
-impl<T> Sum for BigNumBase<T>
-where
-    T: Base,
-{
-    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
-        if let Some(elem) = iter.next() {
-            iter.fold(elem, |acc, n| acc + n)
-        } else {
-            Self::from(0)
+    /// Returns `floor(log2(self))`, regardless of the base. This is exact for bases that
+    /// are powers of two and for any value that fits in a `u128`. Past that the
+    /// exponent's contribution is estimated with `f64` math, which is accurate unless the
+    /// value is extremely close to a power of two or the exponent is too large for an
+    /// `f64` to represent it exactly (past `2^53`). Saturates at `u64::MAX`, and like the
+    /// integer version it panics if `self` is 0
+    pub fn ilog2(&self) -> u64 {
+        if self.sig == 0 {
+            panic!("Unable to take the logarithm of 0");
         }
-    }
-}
 
-impl<T> Product for BigNumBase<T>
-where
-    T: Base,
-{
-    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
-        if let Some(elem) = iter.next() {
-            iter.fold(elem, |acc, n| acc * n)
+        let sig_log = self.sig.ilog2() as u64;
+
+        if self.exp == 0 {
+            sig_log
+        } else if T::NUMBER.is_power_of_two() {
+            (self.exp as u128 * T::NUMBER.ilog2() as u128 + sig_log as u128)
+                .try_into()
+                .unwrap_or(u64::MAX)
+        } else if let Some(full) = u32::try_from(self.exp)
+            .ok()
+            .and_then(|exp| (T::NUMBER as u128).checked_pow(exp))
+            .and_then(|pow| pow.checked_mul(self.sig as u128))
+        {
+            // Near powers of two the float estimate below can round up, so compute it
+            // exactly when we can
+            full.ilog2() as u64
         } else {
-            Self::from(0)
+            // Only the fractional part of log2(sig) needs float math, the rest is exact
+            let frac = (self.sig as f64 / (1u64 << sig_log) as f64).log2();
+            let exp_log = self.exp as f64 * (T::NUMBER as f64).log2();
+
+            sig_log.saturating_add((frac + exp_log).floor() as u64)
         }
     }
-}
 
-impl Display for BigNumBase<Decimal> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.exp == 0 {
-            // Precision specifier has special behavior on floats which is undesired
-            // here. Want to force it to string and use the default behavior, e.g.
-            // a max-width setting.
-            let mag = Decimal::get_mag(self.sig);
+    /// Creates a value from a `u128`. Values that fit in a `u64` are converted exactly like
+    /// `From<u64>`, larger values are shifted down to a valid significand, dropping the
+    /// lowest digits. This isn't a `From` impl since having more than one integer `From`
+    /// impl breaks type inference for literals like `BigNum::from(1)`
+    pub fn from_u128(value: u128) -> Self {
+        if let Ok(value) = u64::try_from(value) {
+            return Self::from(value);
+        }
 
-            if mag < 3 {
-                f.write_fmt(format_args!("{}", self.sig))
-            } else if mag < 6 {
-                f.write_fmt(format_args!("{0:.5}k", (self.sig as f64 / 1e3).to_string()))
-            } else if mag < 9 {
-                f.write_fmt(format_args!("{0:.5}m", (self.sig as f64 / 1e6).to_string()))
-            } else if mag < 12 {
-                f.write_fmt(format_args!("{0:.5}b", (self.sig as f64 / 1e9).to_string()))
-            } else if mag < 15 {
-                f.write_fmt(format_args!(
-                    "{0:.5}t",
-                    (self.sig as f64 / 1e12).to_string()
-                ))
-            } else {
-                let res = (self.sig as f64) / 10f64.powi(mag as i32);
+        let ExpRange(min_exp, _) = T::new().exp_range();
+        let adj = T::get_mag_u128(value) - min_exp;
 
-                if res == 10.0 {
-                    f.write_fmt(format_args!("9.999e{}", mag))
-                } else {
-                    f.write_fmt(format_args!("{0:.5}e{1}", res.to_string(), mag))
-                }
-            }
-        } else {
-            let min_exp = self.base.exp_range().min();
-            let res = (self.sig as f64) / 10f64.powi(min_exp as i32);
+        Self::new(T::rshift_u128(value, adj) as u64, adj as u64)
+    }
 
-            if res == 10.0 {
-                f.write_fmt(format_args!("9.999e{}", min_exp as u64 + self.exp))
-            } else {
-                f.write_fmt(format_args!(
-                    "{0:.5}e{1}",
-                    res.to_string(),
-                    min_exp as u64 + self.exp
-                ))
-            }
+    /// Creates a value from an `f64`, dropping anything after the decimal point. Values
+    /// that are too large for a `u128` are converted via their magnitude, so they keep
+    /// about `f64` precision. Negative values saturate to 0 and `f64::INFINITY` saturates
+    /// to `max_value()`. Panics if `value` is NaN
+    pub fn saturating_from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            panic!("Unable to create a BigNum from NaN");
+        } else if value == f64::INFINITY {
+            return Self::max_value();
+        } else if value < u128::MAX as f64 {
+            // Float to int casts saturate, so negative values become 0
+            return Self::from_u128(value as u128);
         }
+
+        let ExpRange(min_exp, _) = T::new().exp_range();
+        let number = T::NUMBER as f64;
+        let shift = value.log(number).floor() as u64 - min_exp as u64;
+
+        Self::new((value / number.powi(shift as i32)) as u64, shift)
     }
-}
 
-impl<T> Mul<f64> for BigNumBase<T>
-where
-    T: Base,
+    /// Returns the power of the base closest to `target` in log space, i.e.
+    /// `T::NUMBER^round(log_B(target))`. Useful for snapping to gridlines on a log scale.
+    /// Since negative powers can't be represented anything below `sqrt(T::NUMBER)`,
+    /// including non-positive values, snaps to 1. Panics if `target` is NaN
+    pub fn nearest_power_of_base_to_f64(target: f64) -> Self {
+        if target.is_nan() {
+            panic!("Unable to find the nearest power of base to NaN");
+        }
+
+        if target <= 0.0 {
+            return Self::power_of_base(0);
+        }
+
+        // Float to int casts saturate, so negative logs become 0
+        Self::power_of_base(target.log(T::NUMBER as f64).round() as u64)
+    }
+
+    /// Returns the sum of the first `terms` terms of the geometric series starting at
+    /// `self` with the given ratio, i.e. `self + self * ratio + ... + self * ratio^(terms -
+    /// 1)`. This uses the closed form `self * (ratio^terms - 1) / (ratio - 1)`, which is
+    /// far more accurate than summing term by term since errors compound with each
+    /// multiplication. Like all float multiplication, expect the result to be accurate to
+    /// roughly `f64` precision. Panics if `ratio` is negative or NaN, or if `ratio^terms`
+    /// overflows an `f64` and `terms` doesn't fit in an `i32`
+    pub fn self_geometric_total(self, ratio: f64, terms: u64) -> Self {
+        use traits::BigNumPow;
+
+        if ratio.is_nan() || ratio < 0.0 {
+            panic!("Unable to sum a geometric series with a ratio of {}", ratio);
+        }
+
+        if terms == 0 {
+            Self::from(0)
+        } else if ratio == 1.0 {
+            self * terms
+        } else if ratio.powf(terms as f64).is_finite() {
+            self * ((ratio.powf(terms as f64) - 1.0) / (ratio - 1.0))
+        } else {
+            // ratio^terms doesn't fit in a float, but at this size subtracting 1 makes no
+            // difference so we can compute the growth as a BigNum instead
+            let terms = i32::try_from(terms).unwrap_or_else(|_| {
+                panic!("Unable to sum more than i32::MAX terms, found {}", terms)
+            });
+            let growth: Self = ratio.pow(terms);
+
+            growth * self * (1.0 / (ratio - 1.0))
+        }
+    }
+
+    /// Returns the sum of the first `n` terms of the geometric series starting at `first`
+    /// with an integer ratio, i.e. `first + first * ratio + ... + first * ratio^(n - 1)`.
+    /// This uses the closed form `first * (ratio^n - 1) / (ratio - 1)`, so it's as precise
+    /// as `pow` (up to `2 * log2(n)` of drift) plus 1 each for the division and the
+    /// multiplication, no matter how many terms there are. Summing term by term drifts by
+    /// about 1 per term instead. Panics if the result would overflow
+    pub fn geometric_sum(first: Self, ratio: Self, n: u64) -> Self {
+        if n == 0 {
+            return Self::from(0);
+        } else if ratio.is_zero() {
+            return first;
+        } else if ratio.is_one() {
+            return first * n;
+        }
+
+        let growth = ratio.checked_pow_u64(n).unwrap_or_else(|| {
+            panic!(
+                "Exponent overflow while summing {} terms of a geometric series",
+                n
+            )
+        });
+
+        // `(ratio^n - 1) / (ratio - 1)` is an integer, so while `ratio^n` is compact the
+        // division is exact. Once it's expanded the `- 1` is absorbed and the division
+        // truncates, which is the 1 of drift mentioned above. Dividing before multiplying
+        // by `first` avoids overflowing early
+        first * ((growth - 1) / (ratio - 1))
+    }
+
+    /// Breaks a compact value into mixed-radix digits, least significant first. Each
+    /// radix gives the size of one unit in terms of the previous one, and the final entry
+    /// holds whatever is left over, so the result has `radices.len() + 1` digits. E.g.
+    /// with radices `[60, 60, 24]` a number of seconds is split into `[seconds, minutes,
+    /// hours, days]`. Panics if the value isn't compact or any radix is 0
+    pub fn to_mixed_radix(self, radices: &[u64]) -> Vec<u64> {
+        if self.exp != 0 {
+            panic!(
+                "Unable to convert non-compact value with sig {} and exp {} to mixed radix",
+                self.sig, self.exp
+            );
+        }
+
+        let mut rest = self.sig;
+        let mut digits = Vec::with_capacity(radices.len() + 1);
+
+        for &radix in radices {
+            if radix == 0 {
+                panic!("Unable to convert to mixed radix with a radix of 0");
+            }
+
+            digits.push(rest % radix);
+            rest /= radix;
+        }
+        digits.push(rest);
+
+        digits
+    }
+
+    /// Returns the number of distinct representable values in the half-open interval
+    /// between `self` and `other`, i.e. `[min, max)`. This shows how densely the type
+    /// covers a given magnitude: every compact value is representable, but past that
+    /// there are only `max_sig - min_sig + 1` values per exponent. Saturates at
+    /// `u64::MAX` if the count doesn't fit
+    pub fn representable_between(self, other: Self) -> u64 {
+        use traits::Step;
+
+        let (min, max) = if self > other {
+            (other, self)
+        } else {
+            (self, other)
+        };
+
+        Self::steps_between(&min, &max).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the smaller of two values. Equivalent to `Ord::min`, provided so it can be
+    /// called without importing anything
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of two values. Equivalent to `Ord::max`, provided so it can be
+    /// called without importing anything
+    pub fn max(self, other: Self) -> Self {
+        if other >= self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the value to the inclusive range `[lo, hi]`. Like `Ord::clamp`, this
+    /// panics if `lo > hi`
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        if lo > hi {
+            panic!(
+                "Unable to clamp with lo = {:?} greater than hi = {:?}",
+                lo, hi
+            );
+        }
+
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+
+    /// Returns true if the value is in the inclusive range `[lo, hi]`. This is the same as
+    /// `(lo..=hi).contains(&self)`. Always false if `lo > hi`
+    pub fn in_range(self, lo: Self, hi: Self) -> bool {
+        lo <= self && self <= hi
+    }
+
+    /// Returns true if the value is in the exclusive range `(lo, hi)`. Always false if
+    /// `lo >= hi`
+    pub fn in_range_exclusive(self, lo: Self, hi: Self) -> bool {
+        lo < self && self < hi
+    }
+
+    /// Returns the value halfway between `self` and `other`, rounding towards the smaller
+    /// one. Unlike `(self + other) / 2` this can't overflow, and the arguments can be in
+    /// either order, which makes it suitable for binary searching
+    pub fn midpoint(self, other: Self) -> Self {
+        let (min, max) = if self > other {
+            (other, self)
+        } else {
+            (self, other)
+        };
+
+        min + (max - min) / 2
+    }
+
+    /// Returns the greatest common divisor of the two values. This is exact for the values
+    /// as they're represented (`sig * T::NUMBER^exp`), even for expanded values, since the
+    /// larger one only needs to be reduced modulo the significand of the smaller one. Of
+    /// course if either value has drifted due to earlier operations the result reflects
+    /// that. The GCD of 0 and `n` is `n`
+    pub fn gcd(self, other: Self) -> Self {
+        if self.sig == 0 {
+            return other;
+        } else if other.sig == 0 {
+            return self;
+        }
+
+        let (hi, lo) = if self.exp >= other.exp {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        // gcd(hi.sig * N^shift * N^lo.exp, lo.sig * N^lo.exp) is
+        // gcd(hi.sig * N^shift mod lo.sig, lo.sig) * N^lo.exp
+        let m = lo.sig as u128;
+        let mut rem = hi.sig as u128 % m;
+        let mut pow = T::NUMBER as u128 % m;
+        let mut shift = hi.exp - lo.exp;
+
+        while shift > 0 {
+            if shift & 1 == 1 {
+                rem = rem * pow % m;
+            }
+            pow = pow * pow % m;
+            shift >>= 1;
+        }
+
+        let (mut a, mut b) = (lo.sig, rem as u64);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        Self::new(a, lo.exp)
+    }
+
+    /// Interpolates linearly from `self` to `other`, i.e. `self + (other - self) * t`.
+    /// `t` is clamped to `[0, 1]`, so `t = 0.0` returns `self` and `t = 1.0` returns
+    /// `other` exactly. Works whichever of the two is larger. Since this uses `Mul<f64>`
+    /// the result is an estimate in the same way
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        if t <= 0.0 {
+            self
+        } else if t >= 1.0 {
+            other
+        } else if other >= self {
+            self + (other - self) * t
+        } else {
+            self - (self - other) * t
+        }
+    }
+
+    /// Returns true if the value is 0. Cheaper than comparing against `BigNum::from(0)`
+    pub fn is_zero(self) -> bool {
+        self.sig == 0 && self.exp == 0
+    }
+
+    /// Returns true if the value is 1. Cheaper than comparing against `BigNum::from(1)`
+    pub fn is_one(self) -> bool {
+        self.sig == 1 && self.exp == 0
+    }
+
+    /// Returns true if the value is exactly `T::NUMBER^k` for some `k`, including 1
+    /// (`k = 0`). E.g. for `BigNumDec` this is true for 1, 1000, and `10^500`
+    #[doc(alias = "is_exact_power")]
+    pub fn is_power_of_base(self) -> bool {
+        if self.exp == 0 {
+            self.sig != 0 && T::pow(T::get_mag(self.sig)) == self.sig
+        } else {
+            self.sig == self.base.sig_range().min()
+        }
+    }
+
+    /// Returns the largest `k` such that `T::NUMBER^k` divides the value exactly, i.e. the
+    /// number of zeros at the end of the value when written in its base. This counts the
+    /// exponent plus any zeros at the end of the significand, so e.g.
+    /// `BigNumDec::new(7, 5)` gives 5 and `BigNumBin::from(64)` gives 6. Since every power
+    /// divides 0, it returns `u64::MAX` for 0
+    pub fn trailing_zeros_in_base(self) -> u64 {
+        if self.sig == 0 {
+            return u64::MAX;
+        }
+
+        let number = T::NUMBER as u64;
+        let (mut sig, mut zeros) = (self.sig, 0);
+
+        while sig % number == 0 {
+            sig /= number;
+            zeros += 1;
+        }
+
+        self.exp.saturating_add(zeros)
+    }
+
+    /// Multiplies by an `f32` factor. This widens `rhs` to `f64`, which is exact, and uses
+    /// the `Mul<f64>` implementation, so NaN, infinity and negative factors behave the same.
+    /// It's a method rather than a `Mul<f32>` impl because a second float impl would stop
+    /// unsuffixed literals like `n * 1.5` from inferring to `f64`
+    pub fn mul_f32(self, rhs: f32) -> Self {
+        self * f64::from(rhs)
+    }
+
+    /// In-place version of `mul_f32`
+    pub fn mul_assign_f32(&mut self, rhs: f32) {
+        *self = self.mul_f32(rhs);
+    }
+
+    /// Scales the value by `permille / 1000` using only integer math, e.g. a rate of 1500
+    /// multiplies by 1.5 and a rate of 25 by 0.025. This avoids the float rounding of
+    /// `Mul<f64>`, but the division truncates so the result can be one less than exact in
+    /// the last digit of the significand, plus one more if the product had to drop a
+    /// digit to stay normalized. Panics if the exponent of the product would overflow
+    pub fn scale_by_permille(self, permille: u64) -> Self {
+        self * permille / 1000
+    }
+
+    /// Returns the largest power of the base that is less than or equal to the value,
+    /// e.g. `BigNumDec::from(7500)` gives `1000`. Since there is no such power for 0 it
+    /// returns 0
+    pub fn floor_to_power(self) -> Self {
+        if self.exp != 0 {
+            Self {
+                sig: self.base.sig_range().min(),
+                ..self
+            }
+        } else if self.sig == 0 {
+            self
+        } else {
+            Self::power_of_base(T::get_mag(self.sig) as u64)
+        }
+    }
+
+    /// Returns the smallest power of the base that is greater than or equal to the value,
+    /// e.g. `BigNumDec::from(7500)` gives `10000`. For 0 this is `T::NUMBER^0 = 1`. Panics
+    /// if the result would overflow the exponent
+    pub fn ceil_to_power(self) -> Self {
+        if self.sig == 0 {
+            Self::from(1)
+        } else if self.is_power_of_base() {
+            self
+        } else if self.exp != 0 {
+            Self {
+                sig: self.base.sig_range().min(),
+                exp: self.exp.checked_add(1).unwrap_or_else(|| {
+                    panic!("Exponent overflow while rounding {:?} up to a power", self)
+                }),
+                ..self
+            }
+        } else {
+            Self::power_of_base(T::get_mag(self.sig) as u64 + 1)
+        }
+    }
+
+    /// Rounds the significand to its top `digits` digits in base `T::NUMBER`, rounding
+    /// half up and zeroing the rest. E.g. `BigNumDec::from(123456)` rounded to 3 digits
+    /// is `123000`, and `BigNumDec::from(99950)` is `100000`. Values that already have
+    /// `digits` or fewer digits are returned unchanged. If rounding up would overflow the
+    /// exponent this rounds down instead. Panics if `digits` is 0
+    pub fn round_to_significant(self, digits: u32) -> Self {
+        if digits == 0 {
+            panic!("Unable to round {:?} to 0 significant digits", self);
+        } else if self.sig == 0 {
+            return self;
+        }
+
+        let len = T::get_mag(self.sig) + 1;
+        if len <= digits {
+            return self;
+        }
+
+        let scale = T::pow_u128(len - digits);
+        let (quot, rem) = (self.sig as u128 / scale, self.sig as u128 % scale);
+        let up = (quot + 1) * scale;
+
+        if rem * 2 < scale || (self.exp == u64::MAX && up > self.base.sig_range().max() as u128) {
+            Self::from_wide(quot * scale, self.exp)
+        } else {
+            // Rounding up can carry into a new digit, e.g. 999 -> 1000
+            Self::from_wide(up, self.exp)
+        }
+    }
+
+    /// Subtracts `rhs` from `self` with far less precision loss than `Sub`. Instead of
+    /// shifting the smaller operand right to line it up with the larger one, this widens
+    /// the larger significand to a `u128` and shifts it left, so the low digits of `rhs`
+    /// are kept. E.g. in base 61, `BigNum::new(min_sig, 1) - 60` gives back `61^10` since
+    /// `60` is smaller than the spacing at that exponent, whereas this returns the exact
+    /// result. It's noticeably slower than `Sub` so only use it where the precision
+    /// matters. Panics if `rhs > self`
+    pub fn lossless_sub(self, rhs: Self) -> Self {
+        let ExpRange(min_exp, max_exp) = self.base.exp_range();
+
+        if rhs > self {
+            panic!("Attempt to subtract \n{:?} from \n{:?}", rhs, self);
+        }
+
+        // Shifting the larger significand by up to `min_exp` always fits in a u128, since
+        // `T::NUMBER^min_exp <= max_sig`
+        let shift = self.exp - rhs.exp;
+        let widen = shift.min(min_exp as u64);
+        let narrow = shift - widen;
+
+        let lhs_sig = T::lshift_u128(self.sig as u128, widen as u32);
+        let rhs_sig = if narrow >= max_exp as u64 {
+            0
+        } else {
+            T::rshift_u128(rhs.sig as u128, narrow as u32)
+        };
+
+        Self::from_wide(lhs_sig - rhs_sig, self.exp - widen)
+    }
+
+    /// Computes `self * a + b` with a single rounding step. The product is kept as a
+    /// `u128` and `b` is added to it before normalizing, so the result is off by about 1
+    /// where `self * a + b` can be off by 2 (one for each operation). Useful for
+    /// accumulations like `acc = acc * rate + income` where the error compounds every
+    /// iteration. Panics if the exponent of the product would overflow a `u64`
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let ExpRange(min_exp, max_exp) = self.base.exp_range();
+
+        let prod_sig = self.sig as u128 * a.sig as u128;
+        let prod_exp = self.exp.checked_add(a.exp).unwrap_or_else(|| {
+            panic!("Exponent overflow while multiplying {:?} and {:?}", self, a)
+        });
+
+        if prod_sig == 0 {
+            return b;
+        } else if b.sig == 0 {
+            return self * a;
+        }
+
+        // The product can have up to twice the digits of a significand, so it may need to
+        // be cut down to fit `add_wide`. Anything dropped here is far below what survives
+        // the final normalization
+        let width = max_exp + min_exp;
+        let prod = match T::get_mag_u128(prod_sig) {
+            mag if mag >= width => {
+                let adj = mag - width + 1;
+                (T::rshift_u128(prod_sig, adj), prod_exp + adj as u64)
+            }
+            _ => (prod_sig, prod_exp),
+        };
+
+        let (res_sig, res_exp) = Self::add_wide(prod, (b.sig as u128, b.exp));
+
+        Self::from_wide(res_sig, res_exp)
+    }
+
+    /// Sums the values while keeping a running total with about twice the digits of a
+    /// normal significand. Each `+` can drop up to 1 from the result, so the default `Sum`
+    /// can be off by up to the number of values, but here the dropped digits are kept
+    /// until the end and the result is only rounded once. E.g. summing 1000 copies of
+    /// the same value this agrees with multiplying it by 1000 to within 1, where the
+    /// default `Sum` can be off by hundreds. This is slower than `Sum`
+    pub fn compensated_sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let (sig, exp) = iter.fold((0, 0), |acc, n| Self::add_wide(acc, (n.sig as u128, n.exp)));
+
+        Self::from_wide(sig, exp)
+    }
+
+    /// Returns the geometric mean of the values, i.e. the `n`-th root of their product.
+    /// The product would overflow the exponent quickly, so this averages the logarithms
+    /// instead. The exponents are summed exactly and only the significands go through
+    /// float math, so the result is accurate to about `f64` precision. If any value is 0
+    /// the result is 0. Panics if the iterator is empty
+    pub fn geometric_mean<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let number = T::NUMBER as f64;
+
+        let (mut count, mut exps, mut logs, mut zero) = (0u128, 0u128, 0f64, false);
+        for n in iter {
+            count += 1;
+
+            if n.sig == 0 {
+                zero = true;
+            } else {
+                exps += n.exp as u128;
+                logs += (n.sig as f64).log(number);
+            }
+        }
+
+        if count == 0 {
+            panic!("Unable to take the geometric mean of an empty iterator");
+        } else if zero {
+            return Self::from(0);
+        }
+
+        // Only the remainder of the exponent sum needs to go through float math
+        Self::from_log(exps / count, ((exps % count) as f64 + logs) / count as f64)
+    }
+
+    // Returns `T::NUMBER^(whole + frac)`, where `frac` is small enough that only
+    // `floor(frac)` needs adding to the exponent
+    fn from_log(whole: u128, frac: f64) -> Self {
+        let base = T::new();
+        let number = T::NUMBER as f64;
+        let SigRange(min_sig, max_sig) = base.sig_range();
+        let ExpRange(min_exp, _) = base.exp_range();
+
+        let int = frac.floor();
+
+        match (whole + int as u128).checked_sub(min_exp as u128) {
+            // Float rounding near the top of the range can push the exponent just past
+            // `u64::MAX`, everything that uses this expects a result no larger than its
+            // input so saturate
+            Some(exp) if exp > u64::MAX as u128 => Self::max_value(),
+            Some(exp) => {
+                let sig = number.powf(frac - int + min_exp as f64) as u64;
+
+                Self::new_saturating(sig.clamp(min_sig, max_sig), exp as u64)
+            }
+            // Small enough to be compact, round to the nearest integer so e.g. a result
+            // of 3.9999999999999996 comes out as 4
+            None => Self::from(number.powf(whole as f64 + frac).round() as u64),
+        }
+    }
+
+    /// Converts this value to another base. Values below `T::NUMBER^max_exp` are converted
+    /// through a `u128` so they're exact up to the target's truncation. Larger values go
+    /// through logarithms, so they keep around 14 significant digits while the exponent is
+    /// small, but for huge exponents only the magnitude is meaningful. Values that are too
+    /// large for the target base saturate to its `max_value()`
+    pub fn convert<U: Base>(self) -> BigNumBase<U> {
+        let ExpRange(_, max_exp) = self.base.exp_range();
+
+        if T::NUMBER == U::NUMBER {
+            BigNumBase {
+                sig: self.sig,
+                exp: self.exp,
+                base: U::new(),
+            }
+        } else if self.exp <= max_exp as u64 {
+            BigNumBase::from_u128(T::lshift_u128(self.sig as u128, self.exp as u32))
+        } else {
+            let target = U::NUMBER as f64;
+            let exp_log = self.exp as f64 * (T::NUMBER as f64).log(target);
+            let whole = exp_log.floor();
+
+            BigNumBase::from_log(
+                whole as u128,
+                exp_log - whole + (self.sig as f64).log(target),
+            )
+        }
+    }
+
+    /// Compares a value with one in another base by converting `other` with `convert` and
+    /// using `fuzzy_eq`. Large values can't be converted exactly, so `margin` should be
+    /// picked with the precision notes on `convert` in mind
+    pub fn eq_across<U: Base>(self, other: BigNumBase<U>, margin: u64) -> bool {
+        self.fuzzy_eq(other.convert(), margin)
+    }
+
+    /// Returns the `n`-th root of this value. This takes an estimate from the logarithm
+    /// and refines it with a Newton step, so expanded results are accurate to within a
+    /// few units of the significand. Compact results get the exact floor of the root.
+    /// Panics if `n` is 0
+    pub fn nth_root(self, n: u32) -> Self {
+        if n == 0 {
+            panic!("Unable to take the 0th root of {:?}", self);
+        } else if n == 1 || self.sig == 0 {
+            return self;
+        }
+
+        let log = (self.sig as f64).log(T::NUMBER as f64);
+        let estimate = Self::from_log(
+            self.exp as u128 / n as u128,
+            ((self.exp % n as u64) as f64 + log) / n as f64,
+        );
+
+        if estimate.exp == 0 {
+            // A Newton step from a small root is badly off whenever the estimate is, since
+            // the error is raised to the power `n - 1`. The root fits in a u64 so fix up the
+            // estimate exactly instead. Compact inputs are compared in u128 so that no
+            // rounding in `checked_pow` can let a power just above `self` through
+            let fits = |r: u64| {
+                if self.exp == 0 {
+                    (r as u128)
+                        .checked_pow(n)
+                        .is_some_and(|p| p <= self.sig as u128)
+                } else {
+                    Self::from(r).checked_pow(n).is_some_and(|p| p <= self)
+                }
+            };
+            let mut root = estimate.sig;
+
+            while root > 0 && !fits(root) {
+                root -= 1;
+            }
+            while fits(root + 1) {
+                root += 1;
+            }
+
+            return Self::from(root);
+        }
+
+        // Newton's method for `r^n = x` is `r' = r + (x / r^(n - 1) - r) / n`. Only the
+        // correction goes through the division by `n`, since it's tiny compared to `r`
+        // this keeps the rounding in the other steps from being magnified
+        let quot = self / estimate.pow(n - 1);
+
+        if quot >= estimate {
+            estimate + (quot - estimate) / n as u64
+        } else {
+            estimate - (estimate - quot) / n as u64
+        }
+    }
+
+    /// Adds two values with `u128` significands, each less than `T::NUMBER^width` where
+    /// `width = min_exp + max_exp`, so the sum can't overflow. Both are lined up at the
+    /// lowest exponent they can be shifted to, and the result is cut back down to `width`
+    /// digits if necessary
+    fn add_wide(lhs: (u128, u64), rhs: (u128, u64)) -> (u128, u64) {
+        let ExpRange(min_exp, max_exp) = T::new().exp_range();
+        let width = max_exp + min_exp;
+
+        if lhs.0 == 0 {
+            return rhs;
+        } else if rhs.0 == 0 {
+            return lhs;
+        }
+
+        let headroom = |sig: u128| (width - 1 - T::get_mag_u128(sig)) as u64;
+        let res_exp = lhs
+            .1
+            .saturating_sub(headroom(lhs.0))
+            .max(rhs.1.saturating_sub(headroom(rhs.0)));
+        let align = |(sig, exp): (u128, u64)| {
+            if exp >= res_exp {
+                T::lshift_u128(sig, (exp - res_exp) as u32)
+            } else if res_exp - exp >= width as u64 {
+                0
+            } else {
+                T::rshift_u128(sig, (res_exp - exp) as u32)
+            }
+        };
+
+        let res_sig = align(lhs) + align(rhs);
+
+        if T::get_mag_u128(res_sig) >= width {
+            let res_exp = res_exp
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("Exponent overflow while adding"));
+
+            (T::rshift_u128(res_sig, 1), res_exp)
+        } else {
+            (res_sig, res_exp)
+        }
+    }
+
+    /// Normalizes a value with a `u128` significand, truncating any extra digits
+    fn from_wide(sig: u128, exp: u64) -> Self {
+        let base = T::new();
+        let SigRange(_, max_sig) = base.sig_range();
+        let ExpRange(min_exp, _) = base.exp_range();
+
+        if sig == 0 {
+            Self {
+                sig: 0,
+                exp: 0,
+                base,
+            }
+        } else if sig > max_sig as u128 {
+            let adj = T::get_mag_u128(sig) - min_exp;
+
+            Self {
+                sig: T::rshift_u128(sig, adj) as u64,
+                exp: exp
+                    .checked_add(adj as u64)
+                    .unwrap_or_else(|| panic!("Exponent overflow while normalizing")),
+                base,
+            }
+        } else {
+            Self::new(sig as u64, exp)
+        }
+    }
+
+    /// Multiplies the value by `T::NUMBER^rhs`, returning `None` if the exponent of the
+    /// result would overflow a `u64`. The `Shl` implementation panics in this case
+    pub fn checked_shl(self, rhs: u64) -> Option<Self> {
+        self.try_shl(rhs).ok()
+    }
+
+    /// Multiplies the value by `T::NUMBER^rhs`, returning `BigNumError::ExpOverflow` if
+    /// the exponent of the result would overflow a `u64`
+    pub fn try_shl(self, rhs: u64) -> Result<Self, BigNumError> {
+        let ExpRange(min_exp, _) = self.base.exp_range();
+
+        if self.exp != 0 {
+            // Already in expanded form
+            Ok(Self {
+                exp: self.exp.checked_add(rhs).ok_or(BigNumError::ExpOverflow)?,
+                ..self
+            })
+        } else if self.sig == 0 {
+            Ok(self)
+        } else {
+            let mag = T::get_mag(self.sig);
+            // The number of orders of magnitude the significand can be increased
+            let adj = min_exp - mag;
+
+            if adj as u64 > rhs {
+                // The result can be made compact
+                Ok(Self {
+                    sig: T::lshift(self.sig, rhs as u32),
+                    exp: 0,
+                    ..self
+                })
+            } else {
+                Ok(Self {
+                    sig: T::lshift(self.sig, adj),
+                    exp: rhs - adj as u64,
+                    ..self
+                })
+            }
+        }
+    }
+
+    /// Divides the value by `T::NUMBER^rhs`, returning `None` if this would shift all of
+    /// the digits out of a compact value (i.e. `rhs` is greater than its magnitude). The
+    /// `Shr` implementation panics in this case
+    pub fn checked_shr(self, rhs: u64) -> Option<Self> {
+        self.try_shr(rhs).ok()
+    }
+
+    /// Divides the value by `T::NUMBER^rhs`, returning `BigNumError::ShiftUnderflow` if
+    /// this would shift all of the digits out of a compact value
+    pub fn try_shr(self, rhs: u64) -> Result<Self, BigNumError> {
+        if self.exp >= rhs {
+            return Ok(Self {
+                exp: self.exp - rhs,
+                ..self
+            });
+        } else if self.sig == 0 {
+            return Ok(self);
+        }
+
+        let mag = T::get_mag(self.sig);
+        let diff = rhs - self.exp;
+
+        if diff > mag as u64 {
+            return Err(BigNumError::ShiftUnderflow);
+        }
+
+        Ok(Self {
+            sig: T::rshift(self.sig, diff as u32),
+            exp: 0,
+            ..self
+        })
+    }
+
+    /// Divides the value by `T::NUMBER^rhs`, returning 0 instead of panicking if all of
+    /// the digits would be shifted out. This matches how `Div` truncates
+    pub fn saturating_shr(self, rhs: u64) -> Self {
+        self.checked_shr(rhs).unwrap_or(Self {
+            sig: 0,
+            exp: 0,
+            base: self.base,
+        })
+    }
+
+    /// Shifts by a signed amount, so `x.shl_signed(-3) == x >> 3`. This isn't provided as
+    /// `Shl<i64>` since a second `Shl` impl would stop `x << 3` from compiling when the
+    /// result type is needed, as the literal could be either type. Panics in the same
+    /// cases as `Shl` and `Shr`
+    pub fn shl_signed(self, rhs: i64) -> Self {
+        if rhs >= 0 {
+            self << rhs as u64
+        } else {
+            self >> rhs.unsigned_abs()
+        }
+    }
+
+    /// Shifts by a signed amount, so `x.shr_signed(-3) == x << 3`. See `shl_signed`
+    pub fn shr_signed(self, rhs: i64) -> Self {
+        if rhs >= 0 {
+            self >> rhs as u64
+        } else {
+            self << rhs.unsigned_abs()
+        }
+    }
+
+    /// Returns the number of base `T::NUMBER` digits in the full value, i.e. its magnitude
+    /// plus one. Since `exp` always counts powers of `T::NUMBER` this works the same way
+    /// for every base, e.g. a `BigNumHex` gives the number of hex digits. 0 has 1 digit.
+    /// Saturates at `u64::MAX`
+    pub fn num_digits(self) -> u64 {
+        self.magnitude().saturating_add(1)
+    }
+
+    /// Parses a string of digits in the given radix, which has to match `T::NUMBER`.
+    /// Digits past 9 are the letters `a` to `z` in either case, so this only works for
+    /// bases up to 36 and returns `ParseError::UnsupportedRadix` for larger ones. Each
+    /// digit is added to the accumulator after multiplying it by the radix, so strings
+    /// longer than the significand lose precision in the same way as the arithmetic
+    /// operations do
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        if radix != T::NUMBER {
+            return Err(ParseError::RadixMismatch {
+                radix,
+                number: T::NUMBER,
+            });
+        } else if radix > 36 {
+            return Err(ParseError::UnsupportedRadix(radix));
+        } else if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        s.chars().try_fold(Self::from(0), |acc, c| {
+            let digit = c.to_digit(radix).ok_or(ParseError::InvalidDigit(c))?;
+
+            Ok(acc * radix as u64 + digit as u64)
+        })
+    }
+
+    /// Writes out the full value in base `T::NUMBER`, i.e. the digits of the significand
+    /// followed by `exp` zeros. Digits past 9 use lowercase letters like `u64::to_string`
+    /// does for hex. Note that the string has `num_digits()` characters, so this is only
+    /// practical for values of moderate size. Panics if `T::NUMBER > 36` since the digits
+    /// can't be written
+    pub fn to_radix_string(self) -> String {
+        if T::NUMBER > 36 {
+            panic!("Unable to write digits in base {}", T::NUMBER);
+        }
+
+        let radix = T::NUMBER as u64;
+        let mut digits = Vec::new();
+        let mut rest = self.sig;
+
+        loop {
+            digits.push(char::from_digit((rest % radix) as u32, radix as u32).unwrap());
+            rest /= radix;
+
+            if rest == 0 {
+                break;
+            }
+        }
+
+        let mut res: String = digits.into_iter().rev().collect();
+        res.extend(std::iter::repeat_n('0', self.exp as usize));
+
+        res
+    }
+}
+
+impl<T> Default for BigNumBase<T>
+where
+    T: Base,
+{
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl<T> PartialEq for BigNumBase<T>
+where
+    T: Base,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.sig == other.sig && self.exp == other.exp
+    }
+}
+
+impl<T> Eq for BigNumBase<T> where T: Base {}
+
+impl<T> Ord for BigNumBase<T>
+where
+    T: Base,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.exp.cmp(&other.exp) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => match self.sig.cmp(&other.sig) {
+                Ordering::Less => Ordering::Less,
+                Ordering::Greater => Ordering::Greater,
+                Ordering::Equal => Ordering::Equal,
+            },
+        }
+    }
+}
+
+impl<T> PartialOrd for BigNumBase<T>
+where
+    T: Base,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> num_traits::Bounded for BigNumBase<T>
+where
+    T: Base,
+{
+    fn min_value() -> Self {
+        Self::min_value()
+    }
+
+    fn max_value() -> Self {
+        Self::max_value()
+    }
+}
+
+/// The epsilon is a margin in units of the significand's last place, and is passed to
+/// `fuzzy_eq`. The default is 1, since a single operation can be off by that much
+#[cfg(feature = "approx")]
+impl<T> approx::AbsDiffEq for BigNumBase<T>
+where
+    T: Base,
+{
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        1.0
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.fuzzy_eq(*other, epsilon as u64)
+    }
+}
+
+/// Values are equal if they are within `epsilon` of each other as in `AbsDiffEq`, or if
+/// their `relative_error` is at most `max_relative`. The default relative margin is
+/// `f64::EPSILON`, which is a few thousand times the drift of a single operation
+#[cfg(feature = "approx")]
+impl<T> approx::RelativeEq for BigNumBase<T>
+where
+    T: Base,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        f64::EPSILON
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.fuzzy_eq(*other, epsilon as u64) || self.relative_error(*other) <= max_relative
+    }
+}
+
+/// Generates compact and expanded values equally often, with the significand uniform over
+/// the valid range. Exponents stop at `u64::MAX - 1` so that adding two generated values
+/// can't overflow. Shrinking moves towards smaller values by lowering the exponent and
+/// halving the significand, and every candidate is normalized
+#[cfg(feature = "quickcheck")]
+impl<T> quickcheck::Arbitrary for BigNumBase<T>
+where
+    T: Base + 'static,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let base = T::new();
+        let SigRange(min_sig, max_sig) = base.sig_range();
+        let sig = u64::arbitrary(g);
+
+        if bool::arbitrary(g) {
+            let sig = match max_sig.checked_add(1) {
+                Some(n) => sig % n,
+                None => sig,
+            };
+
+            Self { sig, exp: 0, base }
+        } else {
+            Self {
+                sig: min_sig + sig % (max_sig - min_sig + 1),
+                exp: u64::arbitrary(g).clamp(1, u64::MAX - 1),
+                base,
+            }
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.sig == 0 {
+            return quickcheck::empty_shrinker();
+        }
+
+        let mut candidates = vec![Self::from(0)];
+
+        if self.exp > 0 {
+            candidates.push(Self::new(self.sig, self.exp / 2));
+            if self.exp / 2 != self.exp - 1 {
+                candidates.push(Self::new(self.sig, self.exp - 1));
+            }
+        }
+        if self.sig > 1 {
+            candidates.push(Self::new(self.sig / 2, self.exp));
+        }
+
+        Box::new(candidates.into_iter())
+    }
+}
+
+impl<T> Add for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let base = self.base;
+        let SigRange(min_sig, max_sig) = base.sig_range();
+        let ExpRange(_, max_exp) = base.exp_range();
+
+        let (max, min) = if self > rhs { (self, rhs) } else { (rhs, self) };
+        let shift = max.exp - min.exp;
+
+        if shift >= max_exp as u64 {
+            // This shift is guaranteed to result in 0 on lhs, no need to compute
+            return max;
+        }
+
+        let result = max.sig.wrapping_add(T::rshift(min.sig, shift as u32));
+
+        let (sig, exp) = if result < max.sig {
+            // How much we need to add to the overflow result to make up for differences
+            // in the significand's range
+            let diff = u64::MAX - max_sig;
+            (min_sig + T::rshift(result + diff, 1), max.exp + 1)
+        } else if T::NUMBER != 2 && result > max_sig {
+            (T::rshift(result, 1), max.exp + 1)
+        } else {
+            (result, max.exp)
+        };
+
+        Self {
+            sig,
+            exp,
+            base: self.base,
+        }
+    }
+}
+
+impl<T> AddAssign for BigNumBase<T>
+where
+    T: Base,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.try_sub(rhs)
+            .unwrap_or_else(|e| panic!("Unable to subtract {:?} from {:?}: {}", rhs, self, e))
+    }
+}
+
+impl<T> SubAssign for BigNumBase<T>
+where
+    T: Base,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Mul for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = BigNumBase<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.try_mul(rhs)
+            .unwrap_or_else(|e| panic!("Unable to multiply {:?} and {:?}: {}", self, rhs, e))
+    }
+}
+
+impl<T> MulAssign for BigNumBase<T>
+where
+    T: Base,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Div for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.try_div(rhs)
+            .unwrap_or_else(|e| panic!("Unable to divide {:?} by {:?}: {}", self, rhs, e))
+    }
+}
+
+impl<T> Shl<u64> for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        self.try_shl(rhs)
+            .unwrap_or_else(|e| panic!("Unable to shift {:?} by {}: {}", self, rhs, e))
+    }
+}
+
+impl<T> Shr<u64> for BigNumBase<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        self.try_shr(rhs)
+            .unwrap_or_else(|e| panic!("Unable to shift {:?} by {}: {}", self, rhs, e))
+    }
+}
+
+impl<T> ShlAssign<u64> for BigNumBase<T>
+where
+    T: Base,
+{
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = *self << rhs;
+    }
+}
+
+impl<T> ShrAssign<u64> for BigNumBase<T>
+where
+    T: Base,
+{
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = *self >> rhs;
+    }
+}
+
+impl<T> Sum for BigNumBase<T>
+where
+    T: Base,
+{
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        if let Some(elem) = iter.next() {
+            iter.fold(elem, |acc, n| acc + n)
+        } else {
+            Self::from(0)
+        }
+    }
+}
+
+impl<T> Product for BigNumBase<T>
+where
+    T: Base,
+{
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        if let Some(elem) = iter.next() {
+            iter.fold(elem, |acc, n| acc * n)
+        } else {
+            Self::from(0)
+        }
+    }
+}
+
+impl<'a, T> Sum<&'a BigNumBase<T>> for BigNumBase<T>
+where
+    T: Base,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<'a, T> Product<&'a BigNumBase<T>> for BigNumBase<T>
+where
+    T: Base,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl BigNumBase<Decimal> {
+    /// Returns a word describing the scale of this value, for use in tooltips and other
+    /// places where the `Display` suffix is too terse. Values below 1000 are "ones", then
+    /// "thousands", "millions", "billions", and "trillions". Past that there are no
+    /// common names so it switches to the power of ten, e.g. "10^15"
+    pub fn magnitude_name(&self) -> String {
+        let mag = self.magnitude();
+
+        match mag {
+            0..3 => "ones".to_string(),
+            3..6 => "thousands".to_string(),
+            6..9 => "millions".to_string(),
+            9..12 => "billions".to_string(),
+            12..15 => "trillions".to_string(),
+            _ => format!("10^{}", mag),
+        }
+    }
+
+    /// Returns the decimal digits of the significand, most significant first. For
+    /// expanded values the full number is these digits followed by `exp` more, which
+    /// aren't tracked (the value is approximated as `sig * 10^exp`). E.g.
+    /// `BigNumDec::from(12345).digits()` is `[1, 2, 3, 4, 5]`, and 0 gives `[0]`
+    pub fn digits(self) -> Vec<u8> {
+        self.sig.to_string().bytes().map(|b| b - b'0').collect()
+    }
+
+    /// Writes out a compact value in full with its digits grouped in threes, e.g.
+    /// `1,234,567` with `sep = ','`. Expanded values are too long for this to be useful,
+    /// so they fall back to scientific notation via `LowerExp`
+    pub fn to_grouped_string(self, sep: char) -> String {
+        if self.exp != 0 {
+            return format!("{:e}", self);
+        }
+
+        let digits = self.sig.to_string();
+        let mut res = String::with_capacity(digits.len() * 4 / 3);
+
+        for (i, c) in digits.chars().enumerate() {
+            if i != 0 && (digits.len() - i) % 3 == 0 {
+                res.push(sep);
+            }
+            res.push(c);
+        }
+
+        res
+    }
+}
+
+impl BigNumBase<Decimal> {
+    /// Formats `sig / 10^mag` with exactly `precision` digits after the decimal point,
+    /// truncating rather than rounding so the result never reaches the next suffix. This
+    /// works on the integer digits directly so there's no float error
+    fn truncated_mantissa(sig: u64, mag: u32, precision: usize) -> String {
+        let divisor = Decimal::pow(mag);
+        let (int, frac) = (sig / divisor, sig % divisor);
+
+        if precision == 0 {
+            return int.to_string();
+        }
+
+        let mut frac = format!("{:0width$}", frac, width = mag as usize);
+        frac.truncate(precision);
+
+        format!("{}.{:0<width$}", int, frac, width = precision)
+    }
+
+    /// Formats the value in engineering notation, where the exponent is a multiple of 3
+    /// and the mantissa is in `[1, 1000)`. E.g. `1.234e17` is written as `123.4e15` (with
+    /// a precision of 1). The mantissa has exactly `precision` digits after the decimal
+    /// point, truncated like the `Display` impl
+    pub fn to_engineering_string(self, precision: usize) -> String {
+        // `magnitude` saturates, so the largest values need the full magnitude in a u128
+        let (sig_mag, mag) = if self.exp == 0 {
+            let mag = self.magnitude() as u32;
+
+            (mag, mag as u128)
+        } else {
+            let min_exp = self.base.exp_range().min();
+
+            (min_exp, min_exp as u128 + self.exp as u128)
+        };
+        let eng_exp = mag - mag % 3;
+
+        // The mantissa is the significand divided by 10^div_mag, leaving the 1 to 3
+        // leading digits as the integer part
+        let div_mag = sig_mag - (mag - eng_exp) as u32;
+
+        format!(
+            "{}e{}",
+            Self::truncated_mantissa(self.sig, div_mag, precision),
+            eng_exp
+        )
+    }
+}
+
+/// Options for `BigNumDec::format_with`. `suffixes[i]` is used for values in
+/// `[10^(3i + 3), 10^(3i + 6))`, so the first entry is for thousands. Values too big for
+/// the list are written in scientific notation, like `Display` does past trillions.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{BigNumDec, DisplayConfig};
+///
+/// let cfg = DisplayConfig::new(&["K", "M", "B", "T", "aa", "ab"]);
+///
+/// assert_eq!(BigNumDec::from(1_500_000_000_000_000).format_with(&cfg), "1.5aa");
+/// assert_eq!(BigNumDec::new(1, 21).format_with(&cfg), "1e21");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayConfig {
+    pub suffixes: Vec<String>,
+    /// The number of digits after the decimal point, which behaves like the precision flag
+    /// for `Display`
+    pub precision: Option<usize>,
+}
+
+impl DisplayConfig {
+    /// Creates a config with the given suffixes and no fixed precision
+    pub fn new(suffixes: &[&str]) -> Self {
+        Self {
+            suffixes: suffixes.iter().map(|s| s.to_string()).collect(),
+            precision: None,
+        }
+    }
+
+    /// Sets the number of digits shown after the decimal point
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+impl Default for DisplayConfig {
+    /// The suffixes used by `Display`
+    fn default() -> Self {
+        Self::new(&["k", "m", "b", "t"])
+    }
+}
+
+impl BigNumBase<Decimal> {
+    /// Formats the value like `Display`, but with the suffixes and precision from `cfg`.
+    /// This allows suffix lists that go past trillions, as is common in idle games
+    pub fn format_with(self, cfg: &DisplayConfig) -> String {
+        let mut res = String::new();
+        self.write_suffixed(&mut res, cfg.precision, &cfg.suffixes)
+            .expect("Writing to a String can't fail");
+
+        res
+    }
+
+    /// Shared logic for `Display` and `format_with`
+    fn write_suffixed<W: std::fmt::Write, S: AsRef<str>>(
+        &self,
+        w: &mut W,
+        precision: Option<usize>,
+        suffixes: &[S],
+    ) -> std::fmt::Result {
+        if self.exp == 0 && self.sig < 1000 {
+            return w.write_fmt(format_args!("{}", self.sig));
+        }
+
+        let (sig_mag, mag) = if self.exp == 0 {
+            let mag = Decimal::get_mag(self.sig);
+            (mag, mag as u64)
+        } else {
+            let min_exp = self.base.exp_range().min();
+            (min_exp, min_exp as u64 + self.exp)
+        };
+
+        let (suffix_mag, suffix) = match suffixes.get((mag / 3 - 1) as usize) {
+            Some(suffix) => (mag - mag % 3, suffix.as_ref().to_string()),
+            None => (mag, format!("e{}", mag)),
+        };
+        // The significand has to be divided by this power of 10 to get the mantissa
+        let div_mag = sig_mag - (mag - suffix_mag) as u32;
+
+        if let Some(p) = precision {
+            w.write_fmt(format_args!(
+                "{}{}",
+                Self::truncated_mantissa(self.sig, div_mag, p),
+                suffix
+            ))
+        } else {
+            // Precision specifier has special behavior on floats which is undesired
+            // here. Want to force it to string and use the default behavior, e.g.
+            // a max-width setting.
+            let res = self.sig as f64 / 10f64.powi(div_mag as i32);
+            let int_digits = (mag - suffix_mag) as usize + 1;
+
+            if res >= 10f64.powi(int_digits as i32) {
+                // The float rounded up to the next power of 10, e.g. 9.999... to 10, so
+                // fall back to the exact digits
+                w.write_fmt(format_args!(
+                    "{}{}",
+                    Self::truncated_mantissa(self.sig, div_mag, 4 - int_digits),
+                    suffix
+                ))
+            } else {
+                w.write_fmt(format_args!("{0:.5}{1}", res.to_string(), suffix))
+            }
+        }
+    }
+}
+
+impl Display for BigNumBase<Decimal> {
+    /// The precision flag sets the number of digits shown after the decimal point, e.g.
+    /// `format!("{:.2}", BigNumDec::from(1001))` gives `1.00k`. Digits past the precision
+    /// are truncated. Without it up to 5 characters of the mantissa are shown
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_suffixed(f, f.precision(), &["k", "m", "b", "t"])
+    }
+}
+
+impl<T> BigNumBase<T>
+where
+    T: Base,
+{
+    /// Shared logic for `LowerExp` and `UpperExp`. The mantissa is in `[1, T::NUMBER)`
+    /// and is written in decimal, while the exponent is a power of `T::NUMBER`. Only
+    /// `Decimal` uses the `e` notation, other bases spell out the radix (`1.5×2^82`) so
+    /// they can't be mistaken for powers of 10. The fractional digits are computed by long
+    /// division so they're exact, and are truncated rather than rounded. Without a
+    /// precision flag up to 3 fractional digits are shown with trailing zeros removed,
+    /// matching the `Display` impl for `Decimal`
+    fn fmt_exp(&self, f: &mut std::fmt::Formatter<'_>, e: char) -> std::fmt::Result {
+        let (mag, divisor) = if self.exp == 0 {
+            let mag = if self.sig == 0 {
+                0
+            } else {
+                T::get_mag(self.sig)
+            };
+
+            (mag as u128, T::pow(mag))
+        } else {
+            let min_exp = self.base.exp_range().min();
+
+            (min_exp as u128 + self.exp as u128, T::pow(min_exp))
+        };
+
+        let (int, mut rem) = (self.sig / divisor, self.sig % divisor);
+        let mut frac = String::new();
+
+        for _ in 0..f.precision().unwrap_or(3) {
+            let next = rem as u128 * 10;
+            frac.push(char::from(b'0' + (next / divisor as u128) as u8));
+            rem = (next % divisor as u128) as u64;
+        }
+
+        if f.precision().is_none() {
+            frac.truncate(frac.trim_end_matches('0').len());
+        }
+
+        let mantissa = if frac.is_empty() {
+            int.to_string()
+        } else {
+            format!("{}.{}", int, frac)
+        };
+
+        if T::NUMBER == 10 {
+            f.write_fmt(format_args!("{}{}{}", mantissa, e, mag))
+        } else {
+            f.write_fmt(format_args!("{}×{}^{}", mantissa, T::NUMBER, mag))
+        }
+    }
+}
+
+/// Shows the raw fields along with the approximate value in scientific notation (see
+/// `LowerExp`), e.g. `BigNumBase<10>(sig=1500000000000000000, exp=3, ≈1.5e21)` or
+/// `BigNumBase<2>(sig=13835058055282163712, exp=19, ≈1.5×2^82)`
+impl<T> Debug for BigNumBase<T>
+where
+    T: Base,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BigNumBase<{}>(sig={}, exp={}, ≈{:e})",
+            T::NUMBER,
+            self.sig,
+            self.exp,
+            self
+        )
+    }
+}
+
+impl<T> LowerExp for BigNumBase<T>
+where
+    T: Base,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+impl<T> UpperExp for BigNumBase<T>
+where
+    T: Base,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_exp(f, 'E')
+    }
+}
+
+impl BigNumBase<Hexadecimal> {
+    /// Shared logic for `LowerHex` and `UpperHex`. Compact values are written like a
+    /// `u64`, while expanded values get a `p+<exp>` suffix where `exp` is the power of 16
+    /// (so each unit is one hex digit shift). The `#` flag adds a `0x` prefix
+    fn fmt_hex(&self, f: &mut std::fmt::Formatter<'_>, upper: bool) -> std::fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        let sig = if upper {
+            format!("{:X}", self.sig)
+        } else {
+            format!("{:x}", self.sig)
+        };
+
+        if self.exp == 0 {
+            f.write_fmt(format_args!("{}{}", prefix, sig))
+        } else {
+            f.write_fmt(format_args!("{}{}p+{}", prefix, sig, self.exp))
+        }
+    }
+}
+
+impl LowerHex for BigNumBase<Hexadecimal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_hex(f, false)
+    }
+}
+
+impl UpperHex for BigNumBase<Hexadecimal> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_hex(f, true)
+    }
+}
+
+impl<T> Mul<f64> for BigNumBase<T>
+where
+    T: Base,
 {
     type Output = Self;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        let min_exp = self.base.exp_range().min();
-        let cutoff_exp = min_exp / 2;
-        let cutoff = T::pow(cutoff_exp);
-        if rhs > cutoff as f64 {
-            if rhs > u64::MAX as f64 {
-                let mag = rhs.log(T::NUMBER as f64).floor() as u64;
-                let diff = mag - min_exp as u64;
-
-                self * Self::new((rhs / (T::NUMBER as f64).powi(diff as i32)) as u64, diff)
-            } else {
-                // Anything after the decimal point won't make a significant difference in
-                // the total
-                self * (rhs.ceil() as u64)
-            }
-        } else {
-            (self * (rhs * cutoff as f64).ceil() as u64) / cutoff
+    fn mul(self, rhs: f64) -> Self::Output {
+        if rhs.is_nan() {
+            panic!("Unable to multiply {:?} by NaN", self);
+        } else if rhs <= 0.0 || self.sig == 0 {
+            return Self::from(0);
+        } else if rhs == f64::INFINITY {
+            return Self::max_value();
+        } else if rhs > u64::MAX as f64 {
+            return self * Self::saturating_from_f64(rhs);
+        }
+
+        // Factors below 1 are scaled up by `T::NUMBER^down` first so none of their digits
+        // are lost, and the result is scaled back down at the end. This is split in two
+        // since `T::NUMBER^down` alone can overflow an `f64` for tiny factors
+        let number = T::NUMBER as f64;
+        let down = if rhs < 1.0 {
+            (-rhs.log(number)).ceil() as u64
+        } else {
+            0
+        };
+        let half = (down / 2) as i32;
+        let scaled = rhs * number.powi(half) * number.powi(down as i32 - half);
+
+        // Now `scaled = mant * 2^shift` exactly, and the product of the significand with
+        // the 53-bit `mant` fits in a `u128`, so it's only rounded once at the end. Since
+        // `scaled < 2^64`, shifting it left can't overflow either
+        let bits = scaled.to_bits();
+        let mant = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let shift = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+        let prod = self.sig as u128 * mant as u128;
+        let prod = if shift < 0 {
+            prod >> -shift
+        } else {
+            prod << shift
+        };
+
+        if self.exp >= down {
+            Self::from_wide(prod, self.exp - down)
+        } else {
+            match down - self.exp {
+                adj if prod == 0 || adj > T::get_mag_u128(prod) as u64 => Self::from(0),
+                adj => Self::from_wide(T::rshift_u128(prod, adj as u32), 0),
+            }
+        }
+    }
+}
+
+impl<T> MulAssign<f64> for BigNumBase<T>
+where
+    T: Base,
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::repeat_n;
+
+    use macros::test_macros::assert_eq_bignum;
+    use rand::distributions::Uniform;
+    use rand::prelude::Distribution;
+    use rand::thread_rng;
+    use traits::{Pred, Succ};
+
+    use super::*;
+    use crate::Binary;
+
+    #[test]
+    fn new_binary_test() {
+        type BigNum = BigNumBase<Binary>;
+        // Check that adjustment is correct, especially around edge cases
+        assert_eq_bignum!(BigNum::new(1, 0), BigNum::new_raw(1, 0));
+        assert_eq_bignum!(BigNum::new(0b100, 2), BigNum::new_raw(0b10000, 0));
+        assert_eq_bignum!(BigNum::new(1 << 62, 20), BigNum::new_raw(1 << 63, 19));
+        assert_eq_bignum!(BigNum::new(1 << 62, 20), BigNum::new_raw(1 << 63, 19));
+    }
+
+    #[test]
+    fn add_binary_test() {
+        type BigNum = BigNumBase<Binary>;
+        assert_eq_bignum!(
+            BigNum::new(0x100, 0) + BigNum::new(0x0100_0000, 4),
+            BigNum::new_raw(0x1000_0100, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0x1000_0000, 32) + BigNum::new(0x0100_0000, 4),
+            BigNum::new_raw(0x1000_0000_1000_0000, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF, 32) + BigNum::new(0x8000_0000, 1),
+            BigNum::new_raw(0x8000_0000_0000_0000, 1)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 1) + 0x1u64,
+            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 1)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 1) + 0x2u64,
+            BigNum::new_raw(0x8000_0000_0000_0000, 2)
+        );
+    }
+
+    #[test]
+    fn add_hex_test() {
+        type BigNum = BigNumBase<Hexadecimal>;
+        assert_eq_bignum!(
+            BigNum::from(0xFFFF_FFFF_FFFF_FFFFu64) + 1u64,
+            BigNum::new_raw(0x1000_0000_0000_0000, 1)
+        );
+        assert_eq_bignum!(
+            BigNum::from(0xFFFF_FFFF_FFFF_FFFEu64) + 1u64,
+            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFEu64, 10) + 0x0100_0000_0000u64,
+            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 10)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFFu64, 0xFFFF_FFFF_FFFF_0000) + 0x0100_0000_0000u64,
+            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_0000)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
+                + BigNum::new(0x1FFF_FFFF_FFFF_FFFF, 0xFFFF_FFF0),
+            BigNum::new_raw(0x1000_0000_0000_0000, 0x1_0000_0000)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
+                + BigNum::new(0x1FFF_FFFF_FFFF_FFFF, 0xFFFF_FFEF),
+            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
+        );
+    }
+
+    #[test]
+    fn add_decimal_test() {
+        type BigNum = BigNumBase<Decimal>;
+
+        assert_eq_bignum!(
+            BigNum::from(1) + BigNum::new(1243123123, 3),
+            BigNum::new_raw(1243123123001, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::from(1000) + BigNum::new(10u64.pow(19) - 1, 3),
+            BigNum::new_raw(10u64.pow(18), 4)
+        );
+        assert_eq_bignum!(
+            BigNum::new(10u64.pow(19) - 1, 13) + BigNum::new(10u64.pow(18), 3),
+            BigNum::new_raw(10u64.pow(18) + 10u64.pow(7) - 1, 14)
+        );
+    }
+
+    #[test]
+    fn add_arbitrary_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumBase<Base61>;
+
+        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+
+        assert_eq_bignum!(
+            BigNum::from(0xFFFF_FFFF_FFFF_FFFEu64) + 1u64,
+            BigNum::new_raw(((u64::MAX as u128 + 1) / 61u128) as u64, 1)
+        );
+        assert_eq_bignum!(BigNum::from(1u64) + 1u64, BigNum::new_raw(2, 0));
+        assert_eq_bignum!(
+            //BigNum::new(max_sig, 10, BASE) + BigNum::new(1, 10, BASE),
+            BigNum::new(max_sig, 10) + BigNum::new(1, 10),
+            BigNum::new_raw(min_sig, 11)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 10) + BigNum::new(61u64, 9),
+            BigNum::new_raw(min_sig, 11)
+        );
+    }
+
+    #[test]
+    fn sub_binary_test() {
+        type BigNum = BigNumBase<Binary>;
+
+        assert_eq_bignum!(
+            BigNum::new(0x100, 32) - BigNum::new(0x0080_0000_0000, 0),
+            BigNum::new_raw(0x0080_0000_0000, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0x1000_0000_0000_0000, 0) - BigNum::new(0x0010_0000_0000_0000, 8),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 48) - BigNum::new(0x8000_0000_0000_0000, 16),
+            BigNum::new(0xFFFF_FFFF_7FFF_FFFF, 48)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 48) - BigNum::new(0xFFFF_FFFF_0000_0000, 48),
+            BigNum::new(0xFFFF_FFFF_0000_0000, 16)
+        );
+        assert_eq_bignum!(
+            BigNum::new(0x8000_0000_0000_0000, 48) - BigNum::new(0xFFFF_FFFF_0000_0000, 16),
+            BigNum::new(0xFFFF_FFFE_0000_0002, 47)
+        );
+    }
+
+    // I won't test each individual base since the logic is the same, but I will test
+    // binary and arbitrary
+    #[test]
+    fn sub_arbitrary_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumBase<Base61>;
+
+        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+
+        // This is an example of how subtraction results in a loss of precision. See
+        // `lossless_sub` for a slower version that casts the sigs to u128 first
+
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 1) - 61u64,
+            BigNum::new_raw(max_sig - 60, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 1) - max_sig,
+            BigNum::new_raw(max_sig - max_sig / 61, 1)
+        );
+        assert_eq_bignum!(
+            BigNum::new(12341098709128730491, 11234) - BigNum::new(12341098709128730491, 11234),
+            BigNum::from(0)
+        )
+    }
+
+    #[test]
+    fn mul_binary_test() {
+        type BigNum = BigNumBase<Binary>;
+        let SigRange(min_sig, max_sig) = Binary::calculate_ranges().1;
+
+        assert_eq_bignum!(
+            BigNum::from(14215125) * BigNum::from(120487091724u64),
+            BigNum::from(120487091724u64 * 14215125)
+        );
+        // 2^63 * 2^63 = 2^126
+        assert_eq_bignum!(
+            BigNum::from(min_sig) * BigNum::from(min_sig),
+            BigNum::new(min_sig, 63)
+        );
+        assert_eq_bignum!(
+            BigNum::from(min_sig) * BigNum::from(min_sig),
+            BigNum::new(min_sig, 63)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 1) * BigNum::new(max_sig, 1),
+            BigNum::new(max_sig - 1, 64 + 2)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 1123) * BigNum::new(max_sig, 11325),
+            BigNum::new(max_sig - 1, 64 + 1123 + 11325)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig - min_sig, 123410923) * BigNum::from(0),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig - min_sig, 123410923) * BigNum::from(1),
+            BigNum::new(max_sig - min_sig, 123410923)
+        );
+    }
+
+    #[test]
+    fn binary_div_test() {
+        type BigNum = BigNumBase<Binary>;
+        let SigRange(min_sig, max_sig) = Binary::calculate_ranges().1;
+
+        assert_eq_bignum!(
+            BigNum::from(123412341234432u64) / BigNum::from(1221314),
+            BigNum::from(123412341234432u64 / 1221314)
+        );
+        assert_eq_bignum!(
+            BigNum::from(123412341234432u64) / BigNum::from(123412341234432u64),
+            BigNum::from(1)
+        );
+        assert_eq_bignum!(
+            BigNum::from(123412341234432u64) / BigNum::from(12341234123412341234u64),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234432u64, 12341234),
+            BigNum::from(1)
+        );
+        assert_eq_bignum!(
+            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234432u64, 12341235),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234433u64, 12341234),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 12341234) / BigNum::new(min_sig, 12341233),
+            BigNum::from(2)
+        );
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 12341234) / BigNum::new(min_sig, 1),
+            BigNum::new(min_sig, 12341234 - 64)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 12341234) / BigNum::new(max_sig, 1),
+            BigNum::new(min_sig, 12341234 - 64)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 12341234) / BigNum::new(min_sig, 1),
+            BigNum::new(max_sig, 12341234 - 64)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 63 + 12341234) / BigNum::new(min_sig, 1),
+            BigNum::new(max_sig, 12341234 - 1)
+        );
+    }
+
+    #[test]
+    fn binary_shifts() {
+        type BigNum = BigNumBase<Binary>;
+
+        assert_eq_bignum!(BigNum::new(0b100, 0) << 1, BigNum::new(0b1000, 0));
+        assert_eq_bignum!(BigNum::new(0b100, 0) << 2, BigNum::new(0b10000, 0));
+        assert_eq_bignum!(BigNum::new(u64::MAX, 1) << 3, BigNum::new(u64::MAX, 4));
+        assert_eq_bignum!(BigNum::new(u64::MAX, 0) << 64, BigNum::new(u64::MAX, 64));
+
+        assert_eq_bignum!(BigNum::new(0b100, 0) >> 1, BigNum::new(0b10, 0));
+        assert_eq_bignum!(BigNum::new(0b100, 0) >> 2, BigNum::new(0b1, 0));
+        assert_eq_bignum!(BigNum::new(u64::MAX, 1) >> 3, BigNum::new(u64::MAX / 4, 0));
+        assert_eq_bignum!(BigNum::new(u64::MAX, 0) >> 63, BigNum::from(1));
+        assert_eq_bignum!(
+            BigNum::new(u64::MAX, 100) >> 105,
+            BigNum::new(u64::MAX / 32, 0)
+        );
+    }
+
+    #[test]
+    fn display_test() {
+        type BigNum = BigNumBase<Decimal>;
+
+        assert_eq!(format!("{}", BigNum::from(1)), "1");
+        assert_eq!(format!("{}", BigNum::from(999)), "999");
+        assert_eq!(format!("{}", BigNum::from(1000)), "1k");
+        assert_eq!(format!("{}", BigNum::from(1001)), "1.001k");
+        assert_eq!(format!("{}", BigNum::from(999999)), "999.9k");
+        assert_eq!(format!("{}", BigNum::from(1000000)), "1m");
+        assert_eq!(format!("{}", BigNum::from(1001000)), "1.001m");
+        assert_eq!(format!("{}", BigNum::from(999999999)), "999.9m");
+        assert_eq!(format!("{}", BigNum::from(1000000000)), "1b");
+        assert_eq!(format!("{}", BigNum::from(1001000000)), "1.001b");
+        assert_eq!(format!("{}", BigNum::from(999999999999)), "999.9b");
+        assert_eq!(format!("{}", BigNum::from(1000000000000)), "1t");
+        assert_eq!(format!("{}", BigNum::from(1001000000000)), "1.001t");
+        assert_eq!(format!("{}", BigNum::from(999999999999999)), "999.9t");
+        assert_eq!(format!("{}", BigNum::from(1000000000000000)), "1e15");
+        assert_eq!(format!("{}", BigNum::from(1001000000000000)), "1.001e15");
+        assert_eq!(format!("{}", BigNum::from(999999999999999999)), "9.999e17");
+        assert_eq!(format!("{}", BigNum::new(9999, 123523)), "9.999e123526");
+        assert_eq!(format!("{}", BigNum::new(9099, 123523)), "9.099e123526");
+        assert_eq!(format!("{}", BigNum::new(999, 123523)), "9.99e123525");
+    }
+
+    #[test]
+    fn display_precision_test() {
+        type BigNum = BigNumBase<Decimal>;
+
+        assert_eq!(format!("{:.0}", BigNum::from(0)), "0");
+        assert_eq!(format!("{:.0}", BigNum::from(999)), "999");
+        assert_eq!(format!("{:.0}", BigNum::from(1001)), "1k");
+        assert_eq!(format!("{:.0}", BigNum::from(999999)), "999k");
+        assert_eq!(format!("{:.0}", BigNum::from(1001000000000000)), "1e15");
+        assert_eq!(format!("{:.0}", BigNum::new(9999, 123523)), "9e123526");
+
+        assert_eq!(format!("{:.2}", BigNum::from(999)), "999");
+        assert_eq!(format!("{:.2}", BigNum::from(1000)), "1.00k");
+        assert_eq!(format!("{:.2}", BigNum::from(1001)), "1.00k");
+        assert_eq!(format!("{:.2}", BigNum::from(999999)), "999.99k");
+        assert_eq!(format!("{:.2}", BigNum::from(1001000)), "1.00m");
+        assert_eq!(format!("{:.2}", BigNum::from(999999999999)), "999.99b");
+        assert_eq!(format!("{:.2}", BigNum::from(1001000000000)), "1.00t");
+        assert_eq!(
+            format!("{:.2}", BigNum::from(999999999999999999)),
+            "9.99e17"
+        );
+        assert_eq!(format!("{:.2}", BigNum::new(9099, 123523)), "9.09e123526");
+
+        assert_eq!(format!("{:.8}", BigNum::from(1001)), "1.00100000k");
+        assert_eq!(format!("{:.8}", BigNum::from(1001001)), "1.00100100m");
+        assert_eq!(format!("{:.8}", BigNum::from(1234567891234)), "1.23456789t");
+        assert_eq!(
+            format!("{:.8}", BigNum::from(1001000000000000)),
+            "1.00100000e15"
+        );
+        assert_eq!(
+            format!("{:.8}", BigNum::new(9999, 123523)),
+            "9.99900000e123526"
+        );
+    }
+
+    #[test]
+    fn test_random_bin() {
+        #![allow(clippy::erasing_op)]
+
+        type BigNum = BigNumBase<Binary>;
+
+        let dist: Uniform<BigNum> = Uniform::new(BigNum::from(0), BigNum::new(1, u64::MAX / 2));
+        let rng = &mut thread_rng();
+        let nums = dist.sample_iter(rng).take(100);
+
+        for n in nums {
+            assert_eq_bignum!(n + n, n * 2);
+            assert_eq_bignum!(n + n + n, n * BigNum::new(3, 0));
+            assert_eq_bignum!(2 * n + 2 * n, 4 * n);
+            assert_eq_bignum!(n / 2 / 16, n / 32);
+            assert_eq_bignum!(n * 0, n / (n.succ()));
+            assert_eq_bignum!(n + 0, n / 1);
+            assert_eq_bignum!(n / n, BigNum::from(1));
+        }
+    }
+
+    #[test]
+    fn sum_test_binary() {
+        type BigNum = BigNumBin;
+
+        let a: [BigNum; 0] = [];
+        let b: [BigNum; 10] = [BigNum::from(100); 10];
+        let c = (0u64..100).map(BigNum::from);
+        let d: [BigNum; 100] = [BigNum::from(1 << 63); 100];
+
+        assert_eq!(BigNum::from(0), a.into_iter().sum());
+        assert_eq!(BigNum::from(1000), b.into_iter().sum());
+        assert_eq!(BigNum::from(4950), c.sum());
+
+        assert_eq!(BigNum::from(1 << 63) * 100, d.into_iter().sum());
+    }
+
+    #[test]
+    fn prod_test_binary() {
+        type BigNum = BigNumBin;
+
+        let a: [BigNum; 0] = [];
+        let b: [BigNum; 10] = [BigNum::from(2); 10];
+        let c: [BigNum; 10] = [BigNum::from(8); 10];
+        let d: [BigNum; 100] = [BigNum::from(1 << 63); 100];
+
+        assert_eq!(BigNum::from(0), a.into_iter().product());
+        assert_eq!(BigNum::from(1024), b.into_iter().product());
+        assert_eq!(BigNum::from(1024 * 1024 * 1024), c.into_iter().product());
+
+        assert_eq!(BigNum::new(1, 63 * 100), d.into_iter().product());
+    }
+
+    #[should_panic]
+    #[test]
+    fn fuzzy_eq_failed1() {
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
+        let b = a + a + a + a + a;
+        let c = 2 * a + 3 * a;
+
+        assert_eq!(b, c);
+    }
+
+    #[should_panic]
+    #[test]
+    fn fuzzy_eq_failed2() {
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
+        let d: BigNum = repeat_n(a, 20).sum();
+        let e = a * 20;
+
+        assert_eq!(d, e)
+    }
+
+    #[test]
+    fn fuzzy_eq_test() {
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
+        let b = a + a + a + a + a;
+        let c = 2 * a + 3 * a;
+        let d: BigNum = repeat_n(a, 20).sum();
+        let e = a * 20;
+
+        // Since we apply 4 operations to b this is a good upper bound
+        assert!(b.fuzzy_eq(c, 4));
+        // Since we apply 20 operations to d this is a good upper bound
+        assert!(d.fuzzy_eq(e, 20));
+    }
+
+    #[test]
+    fn float_mult_test() {
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.0, 1234);
+        let b = BigNum::new(DEC_SIG_RANGE.1, 1234);
+
+        assert_eq!(a * 1.5, a * 3 / 2);
+        assert_eq!(a * 12.5, a * 100 / 8);
+        assert_eq!(a * 0.5, a / 2);
+
+        // Result of adding a to a float that doesn't fit in u64 bounds
+        let a_overflow_res = a * 1e250;
+        let a_exp_res = a * BigNum::new(1, 250);
+        let (min, max) = if a_overflow_res > a_exp_res {
+            (a_exp_res, a_overflow_res)
+        } else {
+            (a_overflow_res, a_exp_res)
+        };
+
+        // Error in result is less than 1/100000 = .001%
+        assert!(max / (max - min) > BigNum::from(100000));
+
+        assert_eq!(b * 1.5, b * 3 / 2);
+        assert_eq!(b * 12.5, b * 100 / 8);
+        assert_eq!(b * 0.5, b / 2);
+
+        let b_overflow_res = b * 1.234e280;
+        let b_exp_res = b * BigNum::new(1234, 277);
+        let (min, max) = if b_overflow_res > b_exp_res {
+            (b_exp_res, b_overflow_res)
+        } else {
+            (b_overflow_res, b_exp_res)
+        };
+
+        // Error in result is less than 1/100000 = .001%
+        assert!(max / (max - min) > BigNum::from(100000));
+    }
+
+    #[test]
+    fn power_of_base_test() {
+        create_default_base!(Base61, 61);
+
+        fn check<T: Base>() {
+            for k in [0, 1, 5, 15, 18, 19, 20, 63, 64, 65, 1000, 123456789] {
+                let pow = BigNumBase::<T>::power_of_base(k);
+
+                assert_eq_bignum!(pow, BigNumBase::<T>::from(1) << k);
+                assert_eq!(pow.magnitude(), k);
+            }
+
+            let mut acc = BigNumBase::<T>::from(1);
+            for k in 0..200 {
+                assert_eq_bignum!(BigNumBase::<T>::power_of_base(k), acc);
+                acc *= T::NUMBER as u64;
+            }
+        }
+
+        check::<Binary>();
+        check::<Octal>();
+        check::<Decimal>();
+        check::<Hexadecimal>();
+        check::<Base61>();
+    }
+
+    #[test]
+    fn running_max_test() {
+        type BigNum = BigNumDec;
+
+        let vals = [
+            BigNum::from(1),
+            BigNum::from(3),
+            BigNum::from(2),
+            BigNum::new(5, 40),
+            BigNum::from(u64::MAX),
+            BigNum::new(6, 40),
+        ];
+        let expected = [
+            BigNum::from(1),
+            BigNum::from(3),
+            BigNum::from(3),
+            BigNum::new(5, 40),
+            BigNum::new(5, 40),
+            BigNum::new(6, 40),
+        ];
+
+        let res: Vec<BigNum> = BigNum::running_max(vals.into_iter()).collect();
+
+        assert_eq!(res, expected);
+        assert_eq!(BigNum::running_max(std::iter::empty()).count(), 0);
+    }
+
+    #[test]
+    fn dwarfs_test() {
+        type BigNum = BigNumDec;
+
+        let small = BigNum::from(100_000);
+        let big = BigNum::power_of_base(20);
+
+        assert!(big.dwarfs(small, 14));
+        assert!(!big.dwarfs(small, 15));
+        assert!(!big.dwarfs(small, 16));
+        assert!(!small.dwarfs(big, 0));
+        assert!(!big.dwarfs(big, 0));
+
+        assert!(BigNum::new(1, 1000).dwarfs(BigNum::new(9, 900), 99));
+        assert!(!BigNum::new(1, 1000).dwarfs(BigNum::new(9, 900), 100));
+
+        assert!(small.dwarfs(BigNum::from(0), u64::MAX));
+        assert!(!BigNum::from(0).dwarfs(BigNum::from(0), 0));
+    }
+
+    #[test]
+    fn magnitude_name_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(BigNum::from(0).magnitude_name(), "ones");
+        assert_eq!(BigNum::from(999).magnitude_name(), "ones");
+        assert_eq!(BigNum::from(1000).magnitude_name(), "thousands");
+        assert_eq!(BigNum::from(999_999).magnitude_name(), "thousands");
+        assert_eq!(BigNum::from(1_000_000).magnitude_name(), "millions");
+        assert_eq!(BigNum::from(1_000_000_000).magnitude_name(), "billions");
+        assert_eq!(
+            BigNum::from(999_999_999_999_999).magnitude_name(),
+            "trillions"
+        );
+        assert_eq!(
+            BigNum::from(1_000_000_000_000_000).magnitude_name(),
+            "10^15"
+        );
+        assert_eq!(BigNum::new(9999, 123523).magnitude_name(), "10^123526");
+    }
+
+    #[test]
+    fn pow_test() {
+        type BigNum = BigNumBin;
+
+        assert_eq_bignum!(BigNum::from(3).pow(4), BigNum::from(81));
+        assert_eq_bignum!(BigNum::from(7).pow(0), BigNum::from(1));
+        assert_eq_bignum!(BigNum::from(0).pow(5), BigNum::from(0));
+        assert_eq_bignum!(BigNum::from(1024).pow(100), BigNum::power_of_base(1000));
+        assert_eq_bignum!(BigNum::new(1, 1000).pow(3), BigNum::new(1, 3000));
+    }
+
+    #[test]
+    fn overflowing_pow_test() {
+        type BigNum = BigNumBin;
+
+        assert_eq!(
+            BigNum::from(1024).overflowing_pow(100),
+            (BigNum::power_of_base(1000), false)
+        );
+        assert_eq!(
+            BigNum::new(1 << 63, u64::MAX / 2).overflowing_pow(4),
+            (BigNum::new(u64::MAX, u64::MAX), true)
+        );
+        assert_eq!(
+            BigNum::new(1 << 63, u64::MAX / 10).overflowing_pow(11),
+            (BigNum::new(u64::MAX, u64::MAX), true)
+        );
+        assert_eq!(
+            BigNum::new(1 << 63, u64::MAX / 10).overflowing_pow(5),
+            (BigNum::new(1 << 63, 5 * (u64::MAX / 10) + 4 * 63), false)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn pow_overflow_test() {
+        BigNumBin::new(1 << 63, u64::MAX / 2).pow(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_overflow_test() {
+        let _ = BigNumBin::new(1 << 63, u64::MAX / 2) * BigNumBin::new(1 << 63, u64::MAX / 2 + 10);
+    }
+
+    #[test]
+    fn bounds_test() {
+        type BigNum = BigNumDec;
+
+        let max = BigNum::max_value();
+        let min = BigNum::min_value();
+
+        assert_eq_bignum!(max, BigNum::new(DEC_SIG_RANGE.1, u64::MAX));
+        assert_eq_bignum!(min, BigNum::from(0));
+        assert_eq_bignum!(max.pred().succ(), max);
+
+        let dist: Uniform<BigNum> = Uniform::new_inclusive(min, max);
+        for n in dist.sample_iter(&mut thread_rng()).take(1000) {
+            assert!(min <= n && n <= max);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_value_succ_test() {
+        BigNumBin::max_value().succ();
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn bounded_test() {
+        use num_traits::Bounded;
+
+        assert_eq!(
+            <BigNumBin as Bounded>::max_value(),
+            BigNumBin::new(u64::MAX, u64::MAX)
+        );
+        assert_eq!(<BigNumBin as Bounded>::min_value(), BigNumBin::from(0));
+    }
+
+    #[test]
+    #[cfg(feature = "approx")]
+    fn approx_test() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        let a = BigNum::new(max_sig, 10);
+        let b = BigNum::new(max_sig - 3, 10);
+        assert!(a.fuzzy_eq(b, 3));
+
+        assert_abs_diff_eq!(a, b, epsilon = 3.0);
+        assert_abs_diff_eq!(BigNum::new(min_sig, 11), a);
+        assert_relative_eq!(a, b);
+        assert_relative_eq!(a, b, epsilon = 0.0);
+        assert_relative_eq!((a * 1000) / 1000, a);
+        assert_relative_eq!(BigNum::from(1000), BigNum::from(1001), max_relative = 0.001);
+        assert_relative_ne!(BigNum::from(1000), BigNum::from(1002), max_relative = 0.001);
+        assert_relative_ne!(a, BigNum::new(max_sig, 11));
+    }
+
+    #[test]
+    #[cfg(feature = "quickcheck")]
+    fn quickcheck_test() {
+        use quickcheck::{quickcheck, Arbitrary, Gen};
+
+        fn add_commutes(a: BigNumBin, b: BigNumBin) -> bool {
+            a + b == b + a
+        }
+        quickcheck(add_commutes as fn(BigNumBin, BigNumBin) -> bool);
+
+        fn normalized(n: BigNumDec) -> bool {
+            n.is_normalized() && n.shrink().all(|m| m.is_normalized() && m < n)
+        }
+        quickcheck(normalized as fn(BigNumDec) -> bool);
+
+        let mut g = Gen::new(100);
+        let mut n = BigNumDec::arbitrary(&mut g);
+        let mut steps = 0;
+
+        // Skipping the shortcut to 0 should still get there in a reasonable number of steps
+        while let Some(m) = n.shrink().nth(1).or_else(|| n.shrink().next()) {
+            n = m;
+            steps += 1;
+        }
+        assert_eq!(n, BigNumDec::from(0));
+        assert!(steps < 200);
+    }
+
+    #[test]
+    fn prestige_layer_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(BigNum::from(0).prestige_layer(3), (0, BigNum::from(0)));
+        assert_eq!(BigNum::from(999).prestige_layer(3), (0, BigNum::from(999)));
+        assert_eq!(
+            BigNum::from(123_000_000).prestige_layer(3),
+            (2, BigNum::from(123))
+        );
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 1000).prestige_layer(100),
+            (10, BigNum::from(DEC_SIG_RANGE.0 * 5))
+        );
+
+        for (n, orders) in [
+            (BigNum::from(123_456_789), 3),
+            (BigNum::new(DEC_SIG_RANGE.1, 1000), 100),
+            (BigNum::new(123_456_789_123, 12345), 7),
+            (BigNum::new(DEC_SIG_RANGE.0, u64::MAX), 1_000_000),
+        ] {
+            let (layer, residual) = n.prestige_layer(orders);
+            let shift = layer * orders;
+
+            assert_eq!(layer, n.magnitude() / orders);
+            assert!(residual << shift <= n);
+            assert!(n < residual.succ() << shift);
+        }
+    }
+
+    #[test]
+    fn exp_format_test() {
+        create_default_base!(Base61, 61);
+
+        assert_eq!(format!("{:e}", BigNumBin::from(0)), "0×2^0");
+        assert_eq!(format!("{:e}", BigNumBin::from(1)), "1×2^0");
+        assert_eq!(format!("{:e}", BigNumBin::from(6)), "1.5×2^2");
+        assert_eq!(format!("{:.2e}", BigNumBin::from(6)), "1.50×2^2");
+        assert_eq!(format!("{:E}", BigNumBin::new(1 << 63, 10)), "1×2^73");
+        assert_eq!(format!("{:.1e}", BigNumBin::new(u64::MAX, 10)), "1.9×2^73");
+
+        assert_eq!(format!("{:e}", BigNumOct::from(0o1400)), "1.5×8^3");
+        assert_eq!(format!("{:e}", BigNumHex::from(0x180)), "1.5×16^2");
+        assert_eq!(format!("{:E}", BigNumHex::new(u64::MAX, 1)), "15.999×16^16");
+
+        assert_eq!(format!("{:e}", BigNumDec::from(999)), "9.99e2");
+        assert_eq!(format!("{:.0e}", BigNumDec::from(999)), "9e2");
+        for n in [
+            BigNumDec::from(1001000000000000),
+            BigNumDec::from(999999999999999999),
+            BigNumDec::new(9999, 123523),
+            BigNumDec::new(9099, 123523),
+            BigNumDec::new(999, 123523),
+        ] {
+            assert_eq!(format!("{:e}", n), format!("{}", n));
+            assert_eq!(format!("{:.6e}", n), format!("{:.6}", n));
+        }
+
+        type BigNum61 = BigNumBase<Base61>;
+        assert_eq!(format!("{:e}", BigNum61::from(30 * 61 * 61)), "30×61^2");
+        assert_eq!(
+            format!("{:e}", BigNum61::from(30 * 61 * 61 + 61 * 61 / 2)),
+            "30.499×61^2"
+        );
+        assert_eq!(
+            format!("{:.2E}", BigNum61::new(61 * 61, 100)),
+            "1.00×61^102"
+        );
+    }
+
+    #[test]
+    fn recurrence_cost_test() {
+        fn naive<T: Base>(n: u64, seed: [BigNumBase<T>; 2], coeffs: [u64; 2]) -> BigNumBase<T> {
+            let [mut prev, mut curr] = seed;
+
+            if n == 0 {
+                return prev;
+            }
+
+            for _ in 1..n {
+                (prev, curr) = (curr, curr * coeffs[0] + prev * coeffs[1]);
+            }
+
+            curr
+        }
+
+        type BigNum = BigNumBin;
+        let fib = [BigNum::from(0), BigNum::from(1)];
+
+        for n in 0..90 {
+            assert_eq_bignum!(
+                BigNum::recurrence_cost(n, fib, [1, 1]),
+                naive(n, fib, [1, 1])
+            );
+        }
+
+        let seed = [BigNum::from(1), BigNum::from(2)];
+        for n in 0..35 {
+            assert_eq_bignum!(
+                BigNum::recurrence_cost(n, seed, [2, 3]),
+                naive(n, seed, [2, 3])
+            );
+        }
+
+        type BigNumD = BigNumDec;
+        let seed = [BigNumD::from(5), BigNumD::from(7)];
+        for n in [100, 500, 1000] {
+            let (a, b) = (
+                BigNumD::recurrence_cost(n, seed, [3, 1]),
+                naive(n, seed, [3, 1]),
+            );
+
+            assert!(a.fuzzy_eq(b, 4 * n), "{:?} {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hex_format_test() {
+        type BigNum = BigNumHex;
+
+        assert_eq!(format!("{:x}", BigNum::from(0)), "0");
+        assert_eq!(format!("{:x}", BigNum::from(0xff)), "ff");
+        assert_eq!(format!("{:#x}", BigNum::from(0xff)), "0xff");
+        assert_eq!(format!("{:X}", BigNum::from(0xabc)), "ABC");
+        assert_eq!(
+            format!("{:#X}", BigNum::from(u64::MAX)),
+            "0xFFFFFFFFFFFFFFFF"
+        );
+
+        assert_eq!(
+            format!("{:x}", BigNum::from(u64::MAX) + 1u64),
+            "1000000000000000p+1"
+        );
+        assert_eq!(
+            format!("{:#x}", BigNum::new(u64::MAX, 1)),
+            "0xffffffffffffffffp+1"
+        );
+        assert_eq!(
+            format!("{:#X}", BigNum::new(HEX_SIG_RANGE.0, 12345)),
+            "0x1000000000000000p+12345"
+        );
+    }
+
+    #[test]
+    fn le_bytes_test() {
+        create_default_base!(Base61, 61);
+
+        fn check<T: Base>() {
+            let SigRange(min_sig, max_sig) = T::new().sig_range();
+
+            for n in [
+                BigNumBase::<T>::from(0),
+                BigNumBase::from(12345),
+                BigNumBase::from(max_sig),
+                BigNumBase::new(min_sig, 1),
+                BigNumBase::new(max_sig, u64::MAX),
+            ] {
+                assert_eq!(BigNumBase::from_le_bytes(n.to_le_bytes()), Some(n));
+            }
+
+            let mut invalid = [0; 16];
+            invalid[..8].copy_from_slice(&(min_sig - 1).to_le_bytes());
+            invalid[8..].copy_from_slice(&5u64.to_le_bytes());
+            assert_eq!(BigNumBase::<T>::from_le_bytes(invalid), None);
+        }
+
+        check::<Binary>();
+        check::<Octal>();
+        check::<Decimal>();
+        check::<Hexadecimal>();
+        check::<Base61>();
+
+        assert_eq!(
+            BigNumBin::new(1 << 63, 2).to_le_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 0x80, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(BigNumDec::from_le_bytes([0xff; 16]), None);
+    }
+
+    #[test]
+    fn ilog2_test() {
+        create_default_base!(Base3, 3);
+
+        for n in 1..5000u64 {
+            assert_eq!(BigNumDec::from(n).ilog2(), n.ilog2() as u64);
+            assert_eq!(BigNumBase::<Base3>::from(n).ilog2(), n.ilog2() as u64);
+        }
+
+        assert_eq!(BigNumBin::new(1 << 63, 100).ilog2(), 163);
+        assert_eq!(BigNumBin::new(u64::MAX, 100).ilog2(), 163);
+        assert_eq!(BigNumHex::new(1 << 60, 10).ilog2(), 100);
+        assert_eq!(BigNumOct::new(1 << 63, 3).ilog2(), 72);
+        assert_eq!(BigNumBin::max_value().ilog2(), u64::MAX);
+
+        // 10^19 * 10^k sits between 2^x and 2^(x+1) for these values
+        let dec = BigNumDec::power_of_base(30);
+        assert_eq!(dec.ilog2(), 99);
+        assert_eq!(dec.pred().ilog2(), 99);
+        assert_eq!(BigNumDec::power_of_base(300).ilog2(), 996);
+
+        // These are 2^64 - 6 and 2^64 + 4, on either side of a power of two boundary
+        assert_eq!(BigNumDec::new(1844674407370955161, 1).ilog2(), 63);
+        assert_eq!(BigNumDec::new(1844674407370955162, 1).ilog2(), 64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_zero_test() {
+        BigNumDec::from(0).ilog2();
+    }
+
+    #[test]
+    fn from_u128_test() {
+        create_default_base!(Base61, 61);
+
+        assert_eq_bignum!(
+            BigNumBin::from_u128(u64::MAX as u128 + 1),
+            BigNumBin::new(1 << 63, 1)
+        );
+        assert_eq_bignum!(BigNumBin::from_u128(12345u128), BigNumBin::from(12345u64));
+        assert_eq_bignum!(
+            BigNumBin::from_u128(u64::MAX as u128),
+            BigNumBin::from(u64::MAX)
+        );
+        assert_eq_bignum!(
+            BigNumBin::from_u128(u128::MAX),
+            BigNumBin::new(u64::MAX, 64)
+        );
+        assert_eq_bignum!(
+            BigNumHex::from_u128(u128::MAX),
+            BigNumHex::new(u64::MAX, 16)
+        );
+
+        assert_eq_bignum!(
+            BigNumDec::from_u128(10u128.pow(38)),
+            BigNumDec::power_of_base(38)
+        );
+        assert_eq_bignum!(
+            BigNumDec::from_u128(123456789 * 10u128.pow(25)),
+            BigNumDec::new(123456789, 25)
+        );
+
+        type BigNum61 = BigNumBase<Base61>;
+        assert_eq_bignum!(
+            BigNum61::from_u128(61u128.pow(21)),
+            BigNum61::power_of_base(21)
+        );
+        assert!(BigNum61::from_u128(u128::MAX).fuzzy_eq(
+            BigNum61::from(u64::MAX) * BigNum61::from(u64::MAX) + BigNum61::from(u64::MAX) * 2u64,
+            3
+        ));
+    }
+
+    #[test]
+    fn nearest_power_of_base_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(1000.0),
+            BigNum::from(1000)
+        );
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(3000.0),
+            BigNum::from(1000)
+        );
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(3200.0),
+            BigNum::from(10000)
+        );
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(1.5e250),
+            BigNum::power_of_base(250)
+        );
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(9e250),
+            BigNum::power_of_base(251)
+        );
+        assert_eq!(BigNum::nearest_power_of_base_to_f64(0.5), BigNum::from(1));
+        assert_eq!(BigNum::nearest_power_of_base_to_f64(0.0), BigNum::from(1));
+        assert_eq!(
+            BigNum::nearest_power_of_base_to_f64(-100.0),
+            BigNum::from(1)
+        );
+
+        assert_eq!(
+            BigNumBin::nearest_power_of_base_to_f64(1000.0),
+            BigNumBin::from(1024)
+        );
+        assert_eq!(
+            BigNumBin::nearest_power_of_base_to_f64(1.4),
+            BigNumBin::from(1)
+        );
+        assert_eq!(
+            BigNumBin::nearest_power_of_base_to_f64(1.5),
+            BigNumBin::from(2)
+        );
+    }
+
+    #[test]
+    fn self_geometric_total_test() {
+        type BigNum = BigNumDec;
+
+        let first = BigNum::new(DEC_SIG_RANGE.0 * 3, 10);
+
+        for ratio in [0.5, 1.0, 1.07, 1.5, 2.0, 10.0] {
+            let mut term = first;
+            let mut sum = BigNum::from(0);
+
+            // The naive sum does a float multiplication per term, each of which drifts, so
+            // this is a loose bound
+            for terms in 0..20 {
+                let total = first.self_geometric_total(ratio, terms);
+                let (min, max) = if total > sum {
+                    (sum, total)
+                } else {
+                    (total, sum)
+                };
+
+                assert!(
+                    max == min || max / (max - min) > BigNum::from(10u64.pow(7)),
+                    "{} {} {:?} {:?}",
+                    ratio,
+                    terms,
+                    total,
+                    sum
+                );
+
+                sum += term;
+                term *= ratio;
+            }
+        }
+
+        assert_eq!(
+            BigNum::from(5).self_geometric_total(2.0, 0),
+            BigNum::from(0)
+        );
+        assert_eq!(
+            BigNum::from(5).self_geometric_total(1.0, 7),
+            BigNum::from(35)
+        );
+    }
+
+    #[test]
+    fn default_test() {
+        create_default_base!(Base61, 61);
+
+        #[derive(Default)]
+        struct Resources {
+            gold: BigNumBin,
+            gems: BigNumBase<Base61>,
+        }
+
+        assert_eq_bignum!(BigNumBin::default(), BigNumBin::from(0));
+        assert_eq_bignum!(
+            BigNumBase::<Base61>::default(),
+            BigNumBase::<Base61>::from(0)
+        );
+
+        let res = Resources::default();
+        assert_eq_bignum!(res.gold, BigNumBin::from(0));
+        assert_eq_bignum!(res.gems, BigNumBase::<Base61>::from(0));
+
+        let mut v = vec![BigNumDec::from(1)];
+        v.resize_with(3, Default::default);
+        assert_eq!(
+            v,
+            [BigNumDec::from(1), BigNumDec::from(0), BigNumDec::from(0)]
+        );
+    }
+
+    #[test]
+    fn checked_pow_test() {
+        type BigNum = BigNumBin;
+
+        assert_eq!(BigNum::new(1 << 63, u64::MAX / 10).checked_pow(1000), None);
+        assert_eq!(
+            BigNum::new(1 << 63, u64::MAX / 10).checked_pow(u32::MAX),
+            None
+        );
+        assert_eq!(BigNum::max_value().checked_pow(2), None);
+
+        assert_eq!(BigNum::from(3).checked_pow(4), Some(BigNum::from(81)));
+        assert_eq!(
+            BigNum::new(1 << 63, 1000).checked_pow(100),
+            Some(BigNum::new(1 << 63, 100 * 1000 + 99 * 63))
+        );
+        assert_eq!(BigNum::max_value().checked_pow(0), Some(BigNum::from(1)));
+        assert_eq!(BigNum::from(0).checked_pow(0), Some(BigNum::from(1)));
+        assert_eq!(BigNum::from(1).checked_pow(u32::MAX), Some(BigNum::from(1)));
+    }
+
+    #[test]
+    fn normalize_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+
+        let mut n = BigNum::from(5);
+        n.exp = 3;
+        assert!(!n.is_normalized());
+        assert_eq_bignum!(n.normalize(), BigNum::new(5, 3));
+        assert!(n.normalize().is_normalized());
+
+        let mut n = BigNum::new(DEC_SIG_RANGE.0, 10);
+        n.sig = u64::MAX;
+        assert!(!n.is_normalized());
+        assert_eq_bignum!(n.normalize(), BigNum::new(u64::MAX, 10));
+
+        let mut n = BigNum::from(0);
+        n.sig = 1234;
+        assert!(n.is_normalized());
+        assert_eq_bignum!(n.normalize(), n);
+
+        let mut n = BigNumBase::<Base61>::new(1, 1);
+        n.sig = 3;
+        n.exp = 100;
+        assert!(!n.is_normalized());
+        assert_eq_bignum!(n.normalize(), BigNumBase::<Base61>::new(3, 100));
+
+        assert!(BigNumBin::max_value().is_normalized());
+        assert_eq_bignum!(BigNumBin::max_value().normalize(), BigNumBin::max_value());
+    }
+
+    #[test]
+    fn to_mixed_radix_test() {
+        type BigNum = BigNumDec;
+
+        // 3 days, 4 hours, 5 minutes, 6 seconds
+        let secs = BigNum::from(3 * 86400 + 4 * 3600 + 5 * 60 + 6);
+        assert_eq!(secs.to_mixed_radix(&[60, 60, 24]), vec![6, 5, 4, 3]);
+
+        assert_eq!(
+            BigNum::from(59).to_mixed_radix(&[60, 60, 24]),
+            vec![59, 0, 0, 0]
+        );
+        assert_eq!(
+            BigNum::from(0).to_mixed_radix(&[60, 60, 24]),
+            vec![0, 0, 0, 0]
+        );
+        assert_eq!(BigNum::from(1234).to_mixed_radix(&[]), vec![1234]);
+
+        // Degrees, arcminutes and arcseconds
+        assert_eq!(
+            BigNumBin::from(720 * 3600 + 61).to_mixed_radix(&[60, 60, 360]),
+            vec![1, 1, 0, 2]
+        );
+        assert_eq!(
+            BigNumBin::from(u64::MAX).to_mixed_radix(&[1 << 32]),
+            vec![u32::MAX as u64, u32::MAX as u64]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_mixed_radix_non_compact_test() {
+        BigNumDec::new(DEC_SIG_RANGE.0, 1).to_mixed_radix(&[60]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_mixed_radix_zero_test() {
+        BigNumDec::from(100).to_mixed_radix(&[60, 0]);
+    }
+
+    #[test]
+    fn parts_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+
+        assert_eq!(
+            BigNum::from_parts(DEC_SIG_RANGE.0 - 1, 1),
+            Err(BigNumError::InvalidSignificand {
+                sig: DEC_SIG_RANGE.0 - 1,
+                exp: 1
+            })
+        );
+        assert_eq!(
+            BigNum::from_parts(0, 1),
+            Err(BigNumError::InvalidSignificand { sig: 0, exp: 1 })
+        );
+        assert_eq!(
+            BigNumBin::from_parts(1 << 62, 10),
+            Err(BigNumError::InvalidSignificand {
+                sig: 1 << 62,
+                exp: 10
+            })
+        );
+        assert_eq!(
+            BigNum::from_parts(DEC_SIG_RANGE.1 + 1, 1),
+            Err(BigNumError::InvalidSignificand {
+                sig: DEC_SIG_RANGE.1 + 1,
+                exp: 1
+            })
+        );
+
+        for n in [
+            BigNum::from(0),
+            BigNum::from(u64::MAX),
+            BigNum::new(DEC_SIG_RANGE.0, 1),
+            BigNum::new(12345, 1000),
+            BigNum::max_value(),
+        ] {
+            let (sig, exp) = n.into_parts();
+            assert_eq_bignum!(BigNum::from_parts(sig, exp).unwrap(), n);
+        }
+
+        let n = BigNumBase::<Base61>::new(3, 100);
+        let (sig, exp) = n.into_parts();
+        assert_eq_bignum!(BigNumBase::<Base61>::from_parts(sig, exp).unwrap(), n);
+    }
+
+    #[test]
+    fn representable_between_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumBase<Base61>;
+
+        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+
+        assert_eq!(
+            BigNum::from(10).representable_between(BigNum::from(100)),
+            90
+        );
+        assert_eq!(
+            BigNum::from(100).representable_between(BigNum::from(10)),
+            90
+        );
+        assert_eq!(BigNum::from(7).representable_between(BigNum::from(7)), 0);
+        assert_eq!(
+            BigNum::from(max_sig).representable_between(BigNum::new(min_sig, 1)),
+            1
+        );
+        assert_eq!(
+            BigNum::new(min_sig, 1).representable_between(BigNum::new(min_sig, 3)),
+            2 * (max_sig - min_sig + 1)
+        );
+        assert_eq!(
+            BigNum::from(0).representable_between(BigNum::max_value()),
+            u64::MAX
+        );
+
+        for (start, end) in [
+            (BigNum::from(max_sig - 20), BigNum::new(min_sig + 20, 1)),
+            (
+                BigNum::new(max_sig - 5, 1000),
+                BigNum::new(min_sig + 7, 1001),
+            ),
+            (BigNum::from(0), BigNum::from(50)),
+        ] {
+            let mut curr = start;
+            let mut count = 0;
+
+            while curr < end {
+                curr = curr.succ();
+                count += 1;
+            }
+
+            assert_eq!(start.representable_between(end), count);
+        }
+    }
+
+    #[test]
+    fn clamp_test() {
+        type BigNum = BigNumDec;
+
+        let lo = BigNum::from(100);
+        let hi = BigNum::new(DEC_SIG_RANGE.0, 5);
+
+        assert_eq_bignum!(BigNum::from(3).clamp(lo, hi), lo);
+        assert_eq_bignum!(BigNum::from(0).clamp(lo, hi), lo);
+        assert_eq_bignum!(BigNum::from(12345).clamp(lo, hi), BigNum::from(12345));
+        assert_eq_bignum!(lo.clamp(lo, hi), lo);
+        assert_eq_bignum!(hi.clamp(lo, hi), hi);
+        assert_eq_bignum!(BigNum::new(DEC_SIG_RANGE.0, 6).clamp(lo, hi), hi);
+        assert_eq_bignum!(BigNum::max_value().clamp(lo, hi), hi);
+        assert_eq_bignum!(BigNum::from(7).clamp(lo, lo), lo);
+
+        assert_eq_bignum!(lo.min(hi), lo);
+        assert_eq_bignum!(hi.min(lo), lo);
+        assert_eq_bignum!(lo.max(hi), hi);
+        assert_eq_bignum!(hi.max(lo), hi);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_invalid_test() {
+        BigNumDec::from(5).clamp(BigNumDec::from(10), BigNumDec::from(1));
+    }
+
+    #[test]
+    fn predicates_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+
+        assert!(BigNum::from(0).is_zero());
+        assert!(!BigNum::from(1).is_zero());
+        assert!(!BigNum::new(DEC_SIG_RANGE.0, 1).is_zero());
+
+        assert!(BigNum::from(1).is_one());
+        assert!(!BigNum::from(0).is_one());
+        assert!(!BigNum::from(10).is_one());
+        assert!(!BigNum::new(DEC_SIG_RANGE.0, 1).is_one());
+
+        assert!(BigNum::from(1).is_power_of_base());
+        assert!(BigNum::from(10).is_power_of_base());
+        assert!(BigNum::from(1000).is_power_of_base());
+        assert!(BigNum::from(DEC_SIG_RANGE.0).is_power_of_base());
+        assert!(BigNum::new(DEC_SIG_RANGE.0, 1).is_power_of_base());
+        assert!(BigNum::power_of_base(500).is_power_of_base());
+        assert!(!BigNum::from(0).is_power_of_base());
+        assert!(!BigNum::from(20).is_power_of_base());
+        assert!(!BigNum::from(999).is_power_of_base());
+        assert!(!BigNum::new(DEC_SIG_RANGE.0 + 1, 1).is_power_of_base());
+        assert!(!BigNum::max_value().is_power_of_base());
+
+        assert!(BigNumBin::from(1 << 40).is_power_of_base());
+        assert!(!BigNumBin::from(3 << 40).is_power_of_base());
+        assert!(BigNumBin::new(1 << 63, 1000).is_power_of_base());
+
+        assert!(BigNumBase::<Base61>::from(61 * 61).is_power_of_base());
+        assert!(!BigNumBase::<Base61>::from(62).is_power_of_base());
+        assert!(BigNumBase::<Base61>::power_of_base(100).is_power_of_base());
+        assert!(BigNumBase::<Base61>::from(0).is_zero());
+        assert!(BigNumBase::<Base61>::from(1).is_one());
+    }
+
+    #[test]
+    fn lossless_sub_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumBase<Base61>;
+
+        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+
+        // `Sub` drops the 60 entirely since it's smaller than one step at exp 1
+        assert_eq_bignum!(BigNum::new(min_sig, 1) - 60u64, BigNum::new(min_sig, 1));
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 1).lossless_sub(60u64.into()),
+            BigNum::new_raw(max_sig - 59, 0)
+        );
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 1).lossless_sub(61u64.into()),
+            BigNum::new_raw(max_sig - 60, 0)
+        );
+
+        // 61^100 - 61^95 keeps the contribution of the smaller operand exactly
+        let exact = BigNum::new(min_sig, 100).lossless_sub(BigNum::new(min_sig, 95));
+        assert_eq_bignum!(exact, BigNum::new(min_sig - min_sig / 61u64.pow(5), 100));
+        assert!(exact.fuzzy_eq(BigNum::new(min_sig, 100) - BigNum::new(min_sig, 95), 1));
+
+        // Subtracting two close values keeps all of the low digits
+        let (a, b) = (BigNum::new(max_sig, 5), BigNum::new(max_sig - 1, 5));
+        assert_eq_bignum!(a.lossless_sub(b), BigNum::from(61u64.pow(5)));
+        assert_eq_bignum!(a - b, a.lossless_sub(b));
+
+        let (a, b) = (BigNum::new(min_sig + 1, 2), BigNum::new(max_sig, 1));
+        assert_eq_bignum!(a.lossless_sub(b), BigNum::from(61 * 61 + 61));
+
+        assert_eq_bignum!(a.lossless_sub(a), BigNum::from(0));
+        assert_eq_bignum!(a.lossless_sub(BigNum::from(0)), a);
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 1000).lossless_sub(BigNum::from(1)),
+            BigNum::new(min_sig, 1000)
+        );
+        assert_eq_bignum!(
+            BigNum::from(12345).lossless_sub(BigNum::from(345)),
+            BigNum::from(12000)
+        );
+
+        type BigNumB = BigNumBin;
+        assert_eq_bignum!(
+            BigNumB::new(1 << 63, 1).lossless_sub(BigNumB::from(1)),
+            BigNumB::from(u64::MAX)
+        );
+        assert_eq_bignum!(
+            BigNumB::new(1 << 63, 63).lossless_sub(BigNumB::from(1)),
+            BigNumB::new(u64::MAX, 62)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn lossless_sub_underflow_test() {
+        let _ = BigNumDec::from(1).lossless_sub(BigNumDec::from(2));
+    }
+
+    #[test]
+    fn mul_add_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq_bignum!(
+            BigNum::from(3).mul_add(BigNum::from(4), BigNum::from(5)),
+            BigNum::from(17)
+        );
+        assert_eq_bignum!(
+            BigNum::from(0).mul_add(BigNum::from(4), BigNum::from(5)),
+            BigNum::from(5)
+        );
+        assert_eq_bignum!(
+            BigNum::from(3).mul_add(BigNum::from(4), BigNum::from(0)),
+            BigNum::from(12)
+        );
+
+        // The product drops a 1 when normalizing and the separate add drops the 9, but
+        // together they carry into the significand
+        let (x, a, b) = (BigNum::from(max_sig), BigNum::from(9), BigNum::from(9));
+        assert_eq_bignum!(x * a + b, BigNum::new(9 * min_sig - 1, 1));
+        assert_eq_bignum!(x.mul_add(a, b), BigNum::new(9 * min_sig, 1));
+
+        // Terms with very different magnitudes
+        assert_eq_bignum!(
+            BigNum::new(min_sig, 100).mul_add(BigNum::from(2), BigNum::from(12345)),
+            BigNum::new(2 * min_sig, 100)
+        );
+        assert_eq_bignum!(
+            BigNum::from(2).mul_add(BigNum::from(3), BigNum::new(min_sig, 100)),
+            BigNum::new(min_sig, 100)
+        );
+        assert_eq_bignum!(
+            BigNum::new(max_sig, 10).mul_add(BigNum::new(max_sig, 10), BigNum::new(max_sig, 10)),
+            BigNum::new(max_sig, 10) * BigNum::new(max_sig, 10)
+        );
+
+        let rate = BigNumBase::<Base61>::from(3);
+        let income = BigNumBase::<Base61>::new(12345678, 3);
+        let (mut fused, mut separate) = (income, income);
+
+        for _ in 0..1000 {
+            fused = fused.mul_add(rate, income);
+            separate = separate * rate + income;
+        }
+
+        // Each separate step can be off by 2, and at most doubles in ulps when it
+        // renormalizes. The fused version should always be at least as large
+        assert!(fused.fuzzy_eq(separate, 4000));
+        assert!(fused >= separate);
+    }
+
+    // The whole point of this test is to take references clippy considers needless
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ref_ops_test() {
+        type BigNum = BigNumDec;
+
+        let (a, b) = (BigNum::new(DEC_SIG_RANGE.0 * 3, 20), BigNum::from(12345));
+
+        assert_eq_bignum!(&a + &b, a + b);
+        assert_eq_bignum!(a + &b, a + b);
+        assert_eq_bignum!(&a + b, a + b);
+
+        assert_eq_bignum!(&a - &b, a - b);
+        assert_eq_bignum!(a - &b, a - b);
+        assert_eq_bignum!(&a - b, a - b);
+
+        assert_eq_bignum!(&a * &b, a * b);
+        assert_eq_bignum!(a * &b, a * b);
+        assert_eq_bignum!(&a * b, a * b);
+
+        assert_eq_bignum!(&a / &b, a / b);
+        assert_eq_bignum!(a / &b, a / b);
+        assert_eq_bignum!(&a / b, a / b);
+
+        let values = [a, b, BigNum::from(7)];
+        assert_eq_bignum!(
+            values.iter().fold(BigNum::from(0), |acc, n| acc + n),
+            values.iter().copied().sum()
+        );
+        assert_eq_bignum!(
+            values.iter().skip(1).fold(values[0], |acc, n| &acc - n),
+            a - b - BigNum::from(7)
+        );
+    }
+
+    #[test]
+    fn u64_lhs_ops_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq_bignum!(5u64 - BigNum::from(2), BigNum::from(3));
+        assert_eq_bignum!(100u64 / BigNum::from(7), BigNum::from(14));
+        assert_eq_bignum!(1000u64 / BigNum::new(DEC_SIG_RANGE.0, 50), BigNum::from(0));
+        assert_eq_bignum!(2u64 * BigNum::from(21), BigNum::from(21) * 2u64);
+        assert_eq_bignum!(2u64 + BigNum::from(21), BigNum::from(21) + 2u64);
+        assert_eq_bignum!(
+            3u64 * BigNum::new(DEC_SIG_RANGE.0, 50),
+            BigNum::new(DEC_SIG_RANGE.0, 50) * 3u64
+        );
+        assert_eq_bignum!(
+            7u64 + BigNum::new(DEC_SIG_RANGE.0, 50),
+            BigNum::new(DEC_SIG_RANGE.0, 50) + 7u64
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn u64_lhs_sub_underflow_test() {
+        let _ = 5u64 - BigNumDec::from(6);
+    }
+
+    #[test]
+    fn checked_shift_test() {
+        type BigNum = BigNumDec;
+
+        // 12345 has a magnitude of 4
+        assert_eq!(BigNum::from(12345).checked_shr(4), Some(BigNum::from(1)));
+        assert_eq!(BigNum::from(12345).checked_shr(5), None);
+        assert_eq!(BigNum::from(12345).checked_shr(2), Some(BigNum::from(123)));
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0, 5).checked_shr(5 + 18),
+            Some(BigNum::from(1))
+        );
+        assert_eq!(BigNum::new(DEC_SIG_RANGE.0, 5).checked_shr(5 + 19), None);
+        assert_eq!(BigNum::from(0).checked_shr(10), Some(BigNum::from(0)));
+
+        assert_eq_bignum!(BigNum::from(12345).saturating_shr(4), BigNum::from(1));
+        assert_eq_bignum!(BigNum::from(12345).saturating_shr(5), BigNum::from(0));
+        assert_eq_bignum!(
+            BigNum::from(12345).saturating_shr(u64::MAX),
+            BigNum::from(0)
+        );
+
+        let top = BigNum::new(DEC_SIG_RANGE.0, u64::MAX - 10);
+        assert_eq!(
+            top.checked_shl(10),
+            Some(BigNum::new(DEC_SIG_RANGE.0, u64::MAX))
+        );
+        assert_eq!(top.checked_shl(11), None);
+        assert_eq!(BigNum::max_value().checked_shl(1), None);
+        assert_eq!(
+            BigNum::max_value().checked_shl(0),
+            Some(BigNum::max_value())
+        );
+        assert_eq!(
+            BigNum::from(1).checked_shl(u64::MAX),
+            Some(BigNum::new(DEC_SIG_RANGE.0, u64::MAX - 18))
+        );
+        assert_eq!(BigNum::from(0).checked_shl(u64::MAX), Some(BigNum::from(0)));
+        assert_eq!(BigNum::from(5).checked_shl(3), Some(BigNum::from(5000)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn shl_overflow_test() {
+        let _ = BigNumDec::max_value() << 1;
+    }
+
+    #[test]
+    fn signed_shift_test() {
+        type BigNum = BigNumDec;
+
+        let values = [
+            BigNum::from(123456789),
+            BigNum::new(DEC_SIG_RANGE.0 * 4, 30),
+            BigNum::new(DEC_SIG_RANGE.1, 1000),
+        ];
+
+        for x in values {
+            for k in [0, 1, 5, 8] {
+                assert_eq_bignum!(x.shl_signed(-k), x >> k as u64);
+                assert_eq_bignum!(x.shr_signed(-k), x << k as u64);
+                assert_eq_bignum!(x.shl_signed(k), x << k as u64);
+                assert_eq_bignum!(x.shr_signed(k), x >> k as u64);
+            }
+        }
+
+        assert_eq_bignum!(
+            BigNum::from(1).shl_signed(i64::MIN.wrapping_add(1).abs()),
+            BigNum::from(1) << i64::MAX as u64
+        );
+        assert_eq_bignum!(
+            BigNum::new(DEC_SIG_RANGE.0, u64::MAX).shl_signed(i64::MIN),
+            BigNum::new(DEC_SIG_RANGE.0, u64::MAX >> 1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_shift_underflow_test() {
+        let _ = BigNumDec::from(123).shl_signed(-3);
+    }
+
+    #[test]
+    fn shift_assign_test() {
+        type BigNum = BigNumBin;
+
+        for x in [
+            BigNum::from(12345),
+            BigNum::new(1 << 63, 10),
+            BigNum::new(u64::MAX, 12341234),
+        ] {
+            let mut y = x;
+            y <<= 5;
+            assert_eq_bignum!(y, x << 5);
+
+            y >>= 5;
+            assert_eq_bignum!(y, x);
+
+            y >>= 3;
+            assert_eq_bignum!(y, x >> 3);
+        }
+    }
+
+    #[test]
+    fn digits_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(BigNum::from(12345).digits(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(BigNum::from(0).digits(), vec![0]);
+        assert_eq!(BigNum::from(7).digits(), vec![7]);
+        assert_eq!(BigNum::from(1000).digits(), vec![1, 0, 0, 0]);
+
+        let n = BigNum::new(DEC_SIG_RANGE.0 * 3 + 42, 100);
+        let digits = n.digits();
+
+        assert_eq!(digits.len(), 19);
+        assert_eq!(digits[0], 3);
+        assert_eq!(digits[17..], [4, 2]);
+        assert_eq!(digits.len() as u64 + n.exp, n.magnitude() + 1);
+    }
+
+    #[test]
+    fn num_digits_test() {
+        assert_eq!(BigNumDec::from(999).num_digits(), 3);
+        assert_eq!(BigNumDec::from(1000).num_digits(), 4);
+        assert_eq!(BigNumDec::new(1, 100).num_digits(), 101);
+        assert_eq!(BigNumDec::from(0).num_digits(), 1);
+        assert_eq!(BigNumDec::from(u64::MAX).num_digits(), 20);
+        assert_eq!(BigNumDec::max_value().num_digits(), u64::MAX);
+
+        assert_eq!(BigNumBin::from(0b1011).num_digits(), 4);
+        assert_eq!(BigNumBin::new(1, 100).num_digits(), 101);
+        assert_eq!(BigNumOct::from(0o777).num_digits(), 3);
+        assert_eq!(BigNumOct::new(0o7, 30).num_digits(), 31);
+        assert_eq!(BigNumHex::from(0xfff).num_digits(), 3);
+        assert_eq!(BigNumHex::new(0xff, 20).num_digits(), 22);
+        assert_eq!(
+            BigNumHex::from(u64::MAX).num_digits(),
+            format!("{:x}", u64::MAX).len() as u64
+        );
+    }
+
+    #[test]
+    fn engineering_string_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(BigNum::new(1234, 14).to_engineering_string(1), "123.4e15");
+        assert_eq!(BigNum::new(1234, 15).to_engineering_string(3), "1.234e18");
+        assert_eq!(BigNum::new(1234, 16).to_engineering_string(2), "12.34e18");
+        assert_eq!(BigNum::new(1234, 16).to_engineering_string(0), "12e18");
+        assert_eq!(BigNum::new(1234, 16).to_engineering_string(4), "12.3400e18");
+
+        assert_eq!(BigNum::from(0).to_engineering_string(2), "0.00e0");
+        assert_eq!(BigNum::from(7).to_engineering_string(2), "7.00e0");
+        assert_eq!(BigNum::from(999).to_engineering_string(1), "999.0e0");
+        assert_eq!(BigNum::from(1000).to_engineering_string(1), "1.0e3");
+        assert_eq!(BigNum::from(98765).to_engineering_string(3), "98.765e3");
+        assert_eq!(BigNum::from(u64::MAX).to_engineering_string(3), "18.446e18");
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.1, 1).to_engineering_string(2),
+            "99.99e18"
+        );
+        assert_eq!(
+            BigNum::max_value().to_engineering_string(2),
+            "9.99e18446744073709551633"
+        );
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.1, u64::MAX - 1).to_engineering_string(2),
+            "999.99e18446744073709551630"
+        );
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 2).to_engineering_string(2),
+            "500.00e18"
+        );
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 1000).to_engineering_string(1),
+            "50.0e1017"
+        );
+    }
+
+    #[test]
+    fn grouped_string_test() {
+        type BigNum = BigNumDec;
+
+        assert_eq!(BigNum::from(1000).to_grouped_string(','), "1,000");
+        assert_eq!(BigNum::from(1234567).to_grouped_string(','), "1,234,567");
+        assert_eq!(BigNum::from(999).to_grouped_string(','), "999");
+        assert_eq!(BigNum::from(0).to_grouped_string(','), "0");
+        assert_eq!(BigNum::from(12345).to_grouped_string('.'), "12.345");
+        assert_eq!(BigNum::from(123456).to_grouped_string(' '), "123 456");
+        assert_eq!(
+            BigNum::from(DEC_SIG_RANGE.1).to_grouped_string(','),
+            "9,999,999,999,999,999,999"
+        );
+        assert_eq!(BigNum::from(u64::MAX).to_grouped_string(','), "1.844e19");
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 100).to_grouped_string(','),
+            "5e118"
+        );
+    }
+
+    #[test]
+    fn format_with_test() {
+        type BigNum = BigNumDec;
+
+        let cfg = DisplayConfig::new(&["K", "M", "B", "T", "Qa", "Qi"]);
+
+        assert_eq!(BigNum::from(999).format_with(&cfg), "999");
+        assert_eq!(BigNum::from(1001).format_with(&cfg), "1.001K");
+        assert_eq!(BigNum::from(999999999).format_with(&cfg), "999.9M");
+        assert_eq!(BigNum::from(1000000000000).format_with(&cfg), "1T");
+        assert_eq!(BigNum::from(1001000000000000).format_with(&cfg), "1.001Qa");
+        assert_eq!(
+            BigNum::from(999999999999999999).format_with(&cfg),
+            "999.9Qa"
+        );
+        assert_eq!(BigNum::from(DEC_SIG_RANGE.0).format_with(&cfg), "1Qi");
+        assert_eq!(BigNum::new(DEC_SIG_RANGE.1, 0).format_with(&cfg), "9.999Qi");
+        assert_eq!(BigNum::new(DEC_SIG_RANGE.1, 2).format_with(&cfg), "999.9Qi");
+        assert_eq!(BigNum::new(DEC_SIG_RANGE.0, 3).format_with(&cfg), "1e21");
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 100).format_with(&cfg),
+            "5e118"
+        );
+        assert_eq!(
+            BigNum::from(1234567).format_with(&cfg.clone().with_precision(2)),
+            "1.23M"
+        );
+
+        // Suffixes past the compact range apply to expanded values too
+        let long: Vec<String> = (0..40).map(|i| format!("s{}", i)).collect();
+        let long = DisplayConfig {
+            suffixes: long,
+            precision: Some(1),
+        };
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 2).format_with(&long),
+            "500.0s5"
+        );
+        assert_eq!(
+            BigNum::new(DEC_SIG_RANGE.0 * 5, 200).format_with(&long),
+            "5.0e218"
+        );
+
+        for n in [
+            BigNum::from(12),
+            BigNum::from(123456),
+            BigNum::from(999999999999999),
+            BigNum::new(9099, 123523),
+        ] {
+            assert_eq!(n.format_with(&DisplayConfig::default()), format!("{}", n));
+        }
+    }
+
+    #[test]
+    fn from_str_radix_test() {
+        create_default_base!(Base36, 36);
+
+        assert_eq!(
+            BigNumDec::from_str_radix("12345678901234567890", 10),
+            Ok(BigNumDec::new(1234567890123456789, 1))
+        );
+        assert_eq!(
+            BigNumDec::from_str_radix("1234567890123456789", 10),
+            Ok(BigNumDec::from(1234567890123456789))
+        );
+        assert_eq!(
+            BigNumDec::from_str_radix(&format!("1{}", "0".repeat(500)), 10),
+            Ok(BigNumDec::new(1, 500))
+        );
+        assert_eq!(BigNumDec::from_str_radix("0", 10), Ok(BigNumDec::from(0)));
+        assert_eq!(BigNumDec::from_str_radix("007", 10), Ok(BigNumDec::from(7)));
+
+        assert_eq!(
+            BigNumHex::from_str_radix("DEADbeef", 16),
+            Ok(BigNumHex::from(0xdeadbeef))
+        );
+        assert_eq!(
+            BigNumHex::from_str_radix("ffffffffffffffff", 16),
+            Ok(BigNumHex::from(u64::MAX))
+        );
+        assert_eq!(
+            BigNumHex::from_str_radix(&format!("ff{}", "0".repeat(100)), 16),
+            Ok(BigNumHex::new(0xff, 100))
+        );
+        assert_eq!(
+            BigNumBase::<Base36>::from_str_radix("zz", 36),
+            Ok(BigNumBase::<Base36>::from(36 * 36 - 1))
+        );
+
+        assert_eq!(
+            BigNumDec::from_str_radix("12a4", 10),
+            Err(ParseError::InvalidDigit('a'))
+        );
+        assert_eq!(
+            BigNumHex::from_str_radix("12g4", 16),
+            Err(ParseError::InvalidDigit('g'))
+        );
+        assert_eq!(BigNumDec::from_str_radix("", 10), Err(ParseError::Empty));
+        assert_eq!(
+            BigNumDec::from_str_radix("1234", 16),
+            Err(ParseError::RadixMismatch {
+                radix: 16,
+                number: 10
+            })
+        );
+
+        create_default_base!(Base37, 37);
+        assert_eq!(
+            BigNumBase::<Base37>::from_str_radix("10", 37),
+            Err(ParseError::UnsupportedRadix(37))
+        );
+    }
+
+    #[test]
+    fn to_radix_string_test() {
+        create_default_base!(Base36, 36);
+
+        assert_eq!(BigNumDec::new(123, 5).to_radix_string(), "12300000");
+        assert_eq!(BigNumDec::from(0).to_radix_string(), "0");
+        assert_eq!(
+            BigNumDec::new(DEC_SIG_RANGE.0 * 4 + 2, 3).to_radix_string(),
+            format!("4{}2000", "0".repeat(17))
+        );
+        assert_eq!(
+            BigNumDec::new(1, 100).to_radix_string(),
+            format!("1{}", "0".repeat(100))
+        );
+
+        assert_eq!(BigNumBin::from(0b1011).to_radix_string(), "1011");
+        assert_eq!(
+            BigNumBin::from(u64::MAX).to_radix_string(),
+            format!("{:b}", u64::MAX)
+        );
+        assert_eq!(
+            BigNumBin::new(1 << 63, 3).to_radix_string(),
+            format!("1{}", "0".repeat(66))
+        );
+        assert_eq!(BigNumOct::new(0o17, 2).to_radix_string(), "1700");
+        assert_eq!(
+            BigNumHex::new(0xdeadbeef, 20).to_radix_string(),
+            format!("deadbeef{}", "0".repeat(20))
+        );
+        assert_eq!(
+            BigNumBase::<Base36>::from(36 * 35 + 10).to_radix_string(),
+            "za"
+        );
+    }
+
+    #[test]
+    fn new_saturating_test() {
+        type BigNum = BigNumDec;
+
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq_bignum!(
+            BigNum::new_saturating(max_sig, u64::MAX),
+            BigNum::max_value()
+        );
+        assert_eq_bignum!(
+            BigNum::new_saturating(u64::MAX, u64::MAX),
+            BigNum::max_value()
+        );
+        assert_eq_bignum!(
+            BigNum::new_saturating(max_sig + 1, u64::MAX),
+            BigNum::max_value()
+        );
+        assert_eq_bignum!(
+            BigNum::new_saturating(min_sig, u64::MAX),
+            BigNum::new(min_sig, u64::MAX)
+        );
+        assert_eq_bignum!(
+            BigNum::new_saturating(u64::MAX, u64::MAX - 1),
+            BigNum::new(u64::MAX, u64::MAX - 1)
+        );
+        assert_eq_bignum!(BigNum::new_saturating(123, 4), BigNum::new(123, 4));
+        assert_eq_bignum!(
+            BigNumBin::new_saturating(u64::MAX, u64::MAX),
+            BigNumBin::max_value()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_overflow_test() {
+        BigNumDec::new(u64::MAX, u64::MAX);
+    }
+
+    #[test]
+    fn ref_sum_product_test() {
+        type BigNum = BigNumDec;
+
+        let values = [
+            BigNum::from(3),
+            BigNum::new(DEC_SIG_RANGE.0 * 2, 10),
+            BigNum::from(12345),
+            BigNum::new(DEC_SIG_RANGE.1, 3),
+        ];
+
+        let sum: BigNum = values.iter().sum();
+        let product: BigNum = values.iter().product();
+
+        assert_eq_bignum!(sum, values.into_iter().sum());
+        assert_eq_bignum!(product, values.into_iter().product());
+
+        let v = [BigNum::from(2), BigNum::from(5), BigNum::from(7)];
+        assert_eq_bignum!(v.iter().sum::<BigNum>(), BigNum::from(14));
+        assert_eq_bignum!(v.iter().product::<BigNum>(), BigNum::from(70));
+        assert_eq_bignum!(v[..0].iter().sum::<BigNum>(), BigNum::from(0));
+    }
+
+    #[test]
+    fn compensated_sum_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
+        let expected = a * 1000;
+
+        let naive: BigNum = repeat_n(a, 1000).sum();
+        let compensated = BigNum::compensated_sum(repeat_n(a, 1000));
+
+        assert!(!naive.fuzzy_eq(expected, 10));
+        assert!(compensated.fuzzy_eq(expected, 1));
+
+        assert_eq_bignum!(
+            BigNum::compensated_sum((1..=100).map(BigNum::from)),
+            BigNum::from(5050)
+        );
+        assert_eq_bignum!(BigNum::compensated_sum(std::iter::empty()), BigNum::from(0));
+        assert_eq_bignum!(BigNum::compensated_sum([BigNum::from(0), a].into_iter()), a);
+
+        // Lots of small values that the default `Sum` drops entirely
+        let big = BigNum::new(DEC_SIG_RANGE.0, 1);
+        let small = repeat_n(BigNum::from(9), 1000);
+        assert_eq_bignum!(
+            std::iter::once(big).chain(small.clone()).sum::<BigNum>(),
+            big
+        );
+        assert_eq_bignum!(
+            BigNum::compensated_sum(std::iter::once(big).chain(small)),
+            BigNum::new(DEC_SIG_RANGE.0 + 900, 1)
+        );
+
+        let b = BigNumBase::<Base61>::new(Base61::calculate_ranges().1 .1, 10);
+        assert!(BigNumBase::<Base61>::compensated_sum(repeat_n(b, 500)).fuzzy_eq(b * 500, 1));
+    }
+
+    #[test]
+    fn const_base_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumBase<Base61>;
+        type ConstNum = BigNumConst<61>;
+
+        assert_eq!(
+            ConstBase::<61>::calculate_ranges(),
+            Base61::calculate_ranges()
+        );
+        assert_eq!(ConstBase::<61>.exp_range(), Base61::new().exp_range());
+        assert_eq!(ConstBase::<61>.sig_range(), Base61::new().sig_range());
+        assert_eq!(ConstBase::<16>.sig_range(), Hexadecimal.sig_range());
+        assert_eq!(ConstBase::<16>.exp_range(), Hexadecimal.exp_range());
+        assert_eq!(ConstBase::<10>.sig_range(), Decimal.sig_range());
+
+        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+        let pairs = [
+            ((min_sig, 0), (max_sig, 0)),
+            ((max_sig, 12), (min_sig + 1234, 10)),
+            ((12341234, 0), (min_sig * 3, 1234)),
+            ((max_sig, 1), (max_sig, 1)),
+        ];
+
+        for ((s1, e1), (s2, e2)) in pairs {
+            let (a, b) = (BigNum::new(s1, e1), BigNum::new(s2, e2));
+            let (ca, cb) = (ConstNum::new(s1, e1), ConstNum::new(s2, e2));
+
+            assert_eq!((ca.sig, ca.exp), (a.sig, a.exp));
+            assert_eq!(((ca + cb).sig, (ca + cb).exp), ((a + b).sig, (a + b).exp));
+            assert_eq!(((ca * cb).sig, (ca * cb).exp), ((a * b).sig, (a * b).exp));
+            assert_eq!(((ca / cb).sig, (ca / cb).exp), ((a / b).sig, (a / b).exp));
+
+            let (hi, lo) = (a.max(b), a.min(b));
+            let (chi, clo) = (ca.max(cb), ca.min(cb));
+            assert_eq!(
+                ((chi - clo).sig, (chi - clo).exp),
+                ((hi - lo).sig, (hi - lo).exp)
+            );
         }
-    }
-}
 
-impl<T> MulAssign<f64> for BigNumBase<T>
-where
-    T: Base,
-{
-    fn mul_assign(&mut self, rhs: f64) {
-        *self = *self * rhs;
+        assert_eq!(
+            BigNumConst::<37>::from(5) * 37u64,
+            BigNumConst::<37>::from(185)
+        );
+        type ConstBase37 = ConstBase<37>;
+        crate::macros::test_macros::test_base!(ConstBase37);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::iter::repeat_n;
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid max_sig for base 10")]
+    fn debug_assert_invariants_test() {
+        // Should be 10^19 - 1
+        #[derive(Clone, Copy, Debug)]
+        struct BrokenDecimal;
+
+        impl Base for BrokenDecimal {
+            const NUMBER: u32 = 10;
+
+            fn new() -> Self {
+                Self
+            }
 
-    use macros::test_macros::assert_eq_bignum;
-    use rand::distributions::Uniform;
-    use rand::prelude::Distribution;
-    use rand::thread_rng;
-    use traits::Succ;
+            fn exp_range(&self) -> ExpRange {
+                ExpRange(18, 19)
+            }
 
-    use super::*;
-    use crate::Binary;
+            fn sig_range(&self) -> SigRange {
+                SigRange(10u64.pow(18), u64::MAX)
+            }
+        }
 
-    #[test]
-    fn new_binary_test() {
-        type BigNum = BigNumBase<Binary>;
-        // Check that adjustment is correct, especially around edge cases
-        assert_eq_bignum!(BigNum::new(1, 0), BigNum::new_raw(1, 0));
-        assert_eq_bignum!(BigNum::new(0b100, 2), BigNum::new_raw(0b10000, 0));
-        assert_eq_bignum!(BigNum::new(1 << 62, 20), BigNum::new_raw(1 << 63, 19));
-        assert_eq_bignum!(BigNum::new(1 << 62, 20), BigNum::new_raw(1 << 63, 19));
+        Binary::debug_assert_invariants();
+        Decimal::debug_assert_invariants();
+        ConstBase::<61>::debug_assert_invariants();
+
+        BigNumBase::<BrokenDecimal>::new(1, 0);
     }
 
     #[test]
-    fn add_binary_test() {
-        type BigNum = BigNumBase<Binary>;
+    fn large_base_test() {
+        create_default_base!(Base100000, 100000);
+        type BigNum = BigNumBase<Base100000>;
+
+        let n = 100000u64;
+        assert_eq!(
+            Base100000::calculate_ranges(),
+            (ExpRange(2, 3), SigRange(n.pow(2), n.pow(3) - 1))
+        );
+
+        let (a, b) = (123_456_789_012_345u64, 98_765_432_109u64);
+        assert_eq_bignum!(BigNum::from(a) + BigNum::from(b), BigNum::from(a + b));
+        assert_eq_bignum!(BigNum::from(a) - BigNum::from(b), BigNum::from(a - b));
+        assert_eq_bignum!(BigNum::from(b) * 100_000, BigNum::from(b * 100_000));
+
+        // 123_456_789_012_345 * 98_765_432_109 = 12_193_263_113_593_897_260_385_605,
+        // which is 12_193_263_113 * 100000^3 after truncating
         assert_eq_bignum!(
-            BigNum::new(0x100, 0) + BigNum::new(0x0100_0000, 4),
-            BigNum::new_raw(0x1000_0100, 0)
+            BigNum::from(a) * BigNum::from(b),
+            BigNum::new(12_193_263_113, 3)
         );
         assert_eq_bignum!(
-            BigNum::new(0x1000_0000, 32) + BigNum::new(0x0100_0000, 4),
-            BigNum::new_raw(0x1000_0000_1000_0000, 0)
+            BigNum::new(n.pow(3) - 1, 10) + BigNum::new(1, 10),
+            BigNum::new(n.pow(2), 11)
         );
+        assert_eq_bignum!(BigNum::new(a, 7) / BigNum::new(a, 5), BigNum::from(n * n));
+
+        // The largest base possible, since `NUMBER^2` still has to fit in a `u64`
+        type MaxNum = BigNumConst<{ u32::MAX }>;
+        let m = u32::MAX as u64;
+        assert_eq!(ConstBase::<{ u32::MAX }>.exp_range(), ExpRange(1, 2));
+        assert_eq_bignum!(MaxNum::from(m) * m, MaxNum::new(m, 1));
+        assert_eq_bignum!(MaxNum::from(m * m - 1) + MaxNum::from(1), MaxNum::new(m, 1));
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF, 32) + BigNum::new(0x8000_0000, 1),
-            BigNum::new_raw(0x8000_0000_0000_0000, 1)
+            MaxNum::new(m + 5, 3) / MaxNum::new(m + 5, 1),
+            MaxNum::new(m, 1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid base 1, bases must be at least 2")]
+    fn degenerate_base_test() {
+        #[derive(Clone, Copy, Debug)]
+        struct Unary;
+
+        impl Base for Unary {
+            const NUMBER: u32 = 1;
+
+            fn new() -> Self {
+                Self
+            }
+
+            fn exp_range(&self) -> ExpRange {
+                Self::calculate_ranges().0
+            }
+
+            fn sig_range(&self) -> SigRange {
+                Self::calculate_ranges().1
+            }
+        }
+
+        Unary::calculate_ranges();
+    }
+
+    #[test]
+    fn relative_error_test() {
+        create_default_base!(Base61, 61);
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        let a = BigNum::new(max_sig, 50);
+        let b = BigNum::new(max_sig - 1, 50);
+        let err = a.relative_error(b);
+        assert_eq!(err, b.relative_error(a));
+        assert!((err * max_sig as f64 - 1.0).abs() < 1e-9);
+
+        // 10^19 - (10^19 - 1) across the exponent boundary
+        let c = BigNum::new(min_sig, 51);
+        let err = c.relative_error(a);
+        assert!((err * (min_sig as f64 * 10.0) - 1.0).abs() < 1e-9);
+
+        assert_eq!(a.relative_error(a), 0.0);
+        assert_eq!(BigNum::from(0).relative_error(BigNum::from(0)), 0.0);
+        assert_eq!(a.relative_error(BigNum::from(0)), 1.0);
+        assert_eq!(BigNum::from(0).relative_error(BigNum::from(5)), 1.0);
+        assert_eq!(BigNum::from(50).relative_error(BigNum::from(100)), 0.5);
+        assert_eq!(
+            BigNum::new(max_sig, 1000).relative_error(BigNum::from(1)),
+            1.0
         );
+
+        let d = BigNumBase::<Base61>::new(Base61::calculate_ranges().1 .1, 12);
+        let err = d.relative_error(d - BigNumBase::new(1, 12));
+        assert!(err > 0.0 && err < 1e-17);
+    }
+
+    #[test]
+    fn midpoint_test() {
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq_bignum!(BigNum::from(2).midpoint(BigNum::from(10)), BigNum::from(6));
+        assert_eq_bignum!(BigNum::from(10).midpoint(BigNum::from(3)), BigNum::from(6));
+        assert_eq_bignum!(BigNum::from(7).midpoint(BigNum::from(7)), BigNum::from(7));
+
+        let max = BigNum::max_value();
+        assert!(max.midpoint(BigNum::from(0)) < max);
+        assert!(max.midpoint(max - BigNum::new(1, u64::MAX)) <= max);
+        assert_eq_bignum!(max.midpoint(max), max);
+
+        let pairs = [
+            (BigNum::new(min_sig, 10), BigNum::new(max_sig, 10)),
+            (BigNum::new(max_sig, 9), BigNum::new(min_sig, 10)),
+            (BigNum::new(12341234, 0), BigNum::new(min_sig * 3, 100)),
+            (BigNum::from(u64::MAX), BigNum::from(1)),
+        ];
+
+        for (a, b) in pairs {
+            let mid = a.midpoint(b);
+            let (lo, hi) = (a.min(b), a.max(b));
+
+            assert!(lo <= mid && mid <= hi);
+            assert_eq_bignum!(mid, b.midpoint(a));
+            // `a + b` can lose the last digit, which dividing by 2 then shifts back in
+            assert!(mid.fuzzy_eq((a + b) / 2, 10));
+        }
+    }
+
+    #[test]
+    fn lerp_test() {
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        let pairs = [
+            (BigNum::from(100), BigNum::from(200)),
+            (BigNum::new(max_sig, 10), BigNum::new(min_sig, 5)),
+            (BigNum::new(min_sig * 3, 100), BigNum::from(12341234)),
+            (BigNum::new(min_sig, 10), BigNum::new(min_sig, 10)),
+        ];
+
+        for (a, b) in pairs {
+            assert_eq_bignum!(a.lerp(b, 0.0), a);
+            assert_eq_bignum!(a.lerp(b, 1.0), b);
+            assert_eq_bignum!(a.lerp(b, -3.0), a);
+            assert_eq_bignum!(a.lerp(b, 12.0), b);
+
+            let half = a.lerp(b, 0.5);
+            assert!(half.relative_error(a.midpoint(b)) < 1e-12);
+            assert!(a.min(b) <= half && half <= a.max(b));
+        }
+
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 1) + 0x1u64,
-            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 1)
+            BigNum::from(100).lerp(BigNum::from(200), 0.25),
+            BigNum::from(125)
         );
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 1) + 0x2u64,
-            BigNum::new_raw(0x8000_0000_0000_0000, 2)
+            BigNum::from(200).lerp(BigNum::from(100), 0.25),
+            BigNum::from(175)
         );
     }
 
     #[test]
-    fn add_hex_test() {
-        type BigNum = BigNumBase<Hexadecimal>;
+    fn gcd_test() {
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq_bignum!(BigNum::from(48).gcd(BigNum::from(36)), BigNum::from(12));
+        assert_eq_bignum!(BigNum::from(36).gcd(BigNum::from(48)), BigNum::from(12));
+        assert_eq_bignum!(BigNum::from(17).gcd(BigNum::from(5)), BigNum::from(1));
+        assert_eq_bignum!(BigNum::from(0).gcd(BigNum::from(5)), BigNum::from(5));
+        assert_eq_bignum!(BigNum::from(5).gcd(BigNum::from(0)), BigNum::from(5));
+        assert_eq_bignum!(BigNum::from(0).gcd(BigNum::from(0)), BigNum::from(0));
+
         assert_eq_bignum!(
-            BigNum::from(0xFFFF_FFFF_FFFF_FFFFu64) + 1u64,
-            BigNum::new_raw(0x1000_0000_0000_0000, 1)
+            BigNum::new(min_sig, 10).gcd(BigNum::new(min_sig, 5)),
+            BigNum::new(min_sig, 5)
         );
+        // 3 * 10^38 is a multiple of 12
         assert_eq_bignum!(
-            BigNum::from(0xFFFF_FFFF_FFFF_FFFEu64) + 1u64,
-            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0)
+            BigNum::new(3 * min_sig, 20).gcd(BigNum::from(12)),
+            BigNum::from(12)
         );
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFEu64, 10) + 0x0100_0000_0000u64,
-            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 10)
+            BigNum::new(max_sig, 1000).gcd(BigNum::from(3)),
+            BigNum::from(3)
         );
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFFu64, 0xFFFF_FFFF_FFFF_0000) + 0x0100_0000_0000u64,
-            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF_FFFF_0000)
+            BigNum::new(max_sig, 1000).gcd(BigNum::from(7)),
+            BigNum::from(1)
         );
+        // 6 * 10^58 is a multiple of 4 * 10^48
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
-                + BigNum::new(0x1FFF_FFFF_FFFF_FFFF, 0xFFFF_FFF0),
-            BigNum::new_raw(0x1000_0000_0000_0000, 0x1_0000_0000)
+            BigNum::new(min_sig * 6, 40).gcd(BigNum::new(min_sig * 4, 30)),
+            BigNum::new(min_sig * 4, 30)
         );
+
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
-                + BigNum::new(0x1FFF_FFFF_FFFF_FFFF, 0xFFFF_FFEF),
-            BigNum::new_raw(0xFFFF_FFFF_FFFF_FFFF, 0xFFFF_FFFF)
+            BigNumBin::new(1 << 63, 100).gcd(BigNumBin::from(3)),
+            BigNumBin::from(1)
+        );
+        assert_eq_bignum!(
+            BigNumBin::new(1 << 63, 100).gcd(BigNumBin::from(96)),
+            BigNumBin::from(32)
         );
     }
 
     #[test]
-    fn add_decimal_test() {
-        type BigNum = BigNumBase<Decimal>;
+    fn rescale_test() {
+        type BigNum = BigNumDec;
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        let a = BigNum::new(min_sig, 10);
+        assert_eq!(a.rescale(12).sig, min_sig / 100);
+        assert_eq!(a.rescale(12).exp, 12);
+        assert_eq_bignum!(a.rescale(12).normalize(), a);
+        assert_eq!(a.rescale(10), a);
+        assert_eq!(a.rescale(9).sig, max_sig);
+        assert_eq!(a.rescale(0).sig, max_sig);
+        assert_eq!(a.rescale(28).sig, 1);
+        assert_eq!(a.rescale(29).sig, 0);
+        assert_eq!(a.rescale(u64::MAX).sig, 0);
+
+        let b = BigNum::new(max_sig, 10);
+        assert!(b.rescale(12).normalize().fuzzy_eq(b, 100));
+        assert!(b.rescale(15).normalize().fuzzy_eq(b, 100_000));
+
+        let c = BigNum::from(12345);
+        assert_eq!(c.rescale(2).sig, 123);
+        assert_eq_bignum!(c.rescale(2).rescale(0), BigNum::from(12300));
+
+        // Lining up two values to add their significands directly
+        let d = BigNum::new(min_sig * 3, 12);
+        let sum = BigNum::new(a.rescale(d.exp).sig + d.sig, d.exp);
+        assert_eq_bignum!(sum, a + d);
+    }
+
+    #[test]
+    fn trailing_zeros_in_base_test() {
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq!(BigNumDec::new(7, 5).trailing_zeros_in_base(), 5);
+        assert_eq!(BigNumBin::from(64).trailing_zeros_in_base(), 6);
+        assert_eq!(BigNumBin::from(96).trailing_zeros_in_base(), 5);
+        assert_eq!(BigNumDec::from(12300).trailing_zeros_in_base(), 2);
+        assert_eq!(BigNumDec::from(7).trailing_zeros_in_base(), 0);
+        assert_eq!(BigNumDec::from(0).trailing_zeros_in_base(), u64::MAX);
+        assert_eq!(BigNumDec::new(min_sig, 10).trailing_zeros_in_base(), 28);
+        assert_eq!(BigNumDec::new(max_sig, 10).trailing_zeros_in_base(), 10);
+        assert_eq!(BigNumDec::max_value().trailing_zeros_in_base(), u64::MAX);
+        assert_eq!(
+            BigNumDec::new(min_sig, u64::MAX).trailing_zeros_in_base(),
+            u64::MAX
+        );
+
+        assert!(BigNumDec::from(1).is_power_of_base());
+        assert!(BigNumDec::from(1000).is_power_of_base());
+        assert!(BigNumDec::new(min_sig, 10).is_power_of_base());
+        assert!(BigNumBin::from(64).is_power_of_base());
+        assert!(!BigNumDec::new(7, 5).is_power_of_base());
+        assert!(!BigNumDec::from(0).is_power_of_base());
+
+        for n in [BigNumDec::from(1000), BigNumDec::new(min_sig, 50)] {
+            assert_eq!(BigNumDec::power_of_base(n.trailing_zeros_in_base()), n);
+        }
+    }
+
+    #[test]
+    fn mul_f64_non_finite_test() {
+        type BigNum = BigNumDec;
 
+        assert_eq_bignum!(BigNum::from(5) * f64::INFINITY, BigNum::max_value());
         assert_eq_bignum!(
-            BigNum::from(1) + BigNum::new(1243123123, 3),
-            BigNum::new_raw(1243123123001, 0)
+            BigNum::new(12345, 1000) * f64::INFINITY,
+            BigNum::max_value()
         );
+        assert_eq_bignum!(BigNum::from(0) * f64::INFINITY, BigNum::from(0));
         assert_eq_bignum!(
-            BigNum::from(1000) + BigNum::new(10u64.pow(19) - 1, 3),
-            BigNum::new_raw(10u64.pow(18), 4)
+            BigNum::from(5) * f64::MAX,
+            BigNum::saturating_from_f64(f64::MAX) * 5
         );
         assert_eq_bignum!(
-            BigNum::new(10u64.pow(19) - 1, 13) + BigNum::new(10u64.pow(18), 3),
-            BigNum::new_raw(10u64.pow(18) + 10u64.pow(7) - 1, 14)
+            BigNumBin::from(1) * f64::MAX,
+            BigNumBin::new(u64::MAX << 11, 960)
+        );
+
+        assert_eq_bignum!(
+            BigNum::saturating_from_f64(f64::INFINITY),
+            BigNum::max_value()
+        );
+        assert_eq_bignum!(BigNum::saturating_from_f64(-5.0), BigNum::from(0));
+        assert_eq_bignum!(
+            BigNum::saturating_from_f64(f64::NEG_INFINITY),
+            BigNum::from(0)
+        );
+        assert_eq_bignum!(BigNum::saturating_from_f64(1234.9), BigNum::from(1234));
+        assert_eq_bignum!(
+            BigNum::saturating_from_f64(1e20),
+            BigNum::new(10u64.pow(18), 2)
         );
+        let n = BigNum::saturating_from_f64(1e300);
+        assert!(n.relative_error(BigNum::new(10u64.pow(18), 282)) < 1e-14);
     }
 
     #[test]
-    fn add_arbitrary_test() {
+    #[should_panic(expected = "by NaN")]
+    fn mul_f64_nan_test() {
+        let _ = BigNumDec::from(5) * f64::NAN;
+    }
+
+    #[test]
+    #[should_panic(expected = "from NaN")]
+    fn saturating_from_f64_nan_test() {
+        BigNumDec::saturating_from_f64(f64::NAN);
+    }
+
+    #[test]
+    fn float_mult_precision_test() {
+        create_default_base!(Base61, 61);
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        for x in [
+            BigNumDec::new(min_sig, 10),
+            BigNumDec::new(max_sig, 1234),
+            BigNumDec::from(123456789),
+        ] {
+            assert_eq_bignum!(x * 1.0, x);
+            assert!(x < x * 1.0001 && x * 1.0001 < x * 1.001);
+            assert!(x * 0.9999 < x);
+            assert!((x * 1.0001).relative_error(x + x / 10000) < 1e-12);
+        }
+
+        let y = BigNumBase::<Base61>::new(Base61::calculate_ranges().1 .1 - 12345, 100);
+        assert_eq_bignum!(y * 1.0, y);
+        assert!(y < y * 1.0001 && y * 1.0001 < y * 1.001);
+        assert_eq_bignum!(
+            BigNumBin::new(u64::MAX, 5) * 1.0,
+            BigNumBin::new(u64::MAX, 5)
+        );
+
+        // Rounds down rather than up
+        assert_eq_bignum!(BigNumDec::from(10) * 1.05, BigNumDec::from(10));
+        assert_eq_bignum!(BigNumDec::from(10) * 0.15, BigNumDec::from(1));
+
+        // Tiny factors scale the exponent down instead of losing everything
+        let z = BigNumDec::new(min_sig, 1000);
+        assert!((z * 1e-300).relative_error(BigNumDec::new(min_sig, 700)) < 1e-12);
+        assert_eq_bignum!(BigNumDec::from(5) * 1e-300, BigNumDec::from(0));
+        assert_eq_bignum!(z * -2.0, BigNumDec::from(0));
+    }
+
+    #[test]
+    fn sub_compact_boundary_test() {
         create_default_base!(Base61, 61);
         type BigNum = BigNumBase<Base61>;
+        let (ExpRange(min_exp, _), SigRange(min_sig, max_sig)) = Base61::calculate_ranges();
 
-        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+        // A difference of 1 in the significand is `61^exp`, which needs a shift of
+        // `adj = min_exp` to normalize
+        let diff = |exp: u64| BigNum::new(min_sig + 1, exp) - BigNum::new(min_sig, exp);
 
+        // adj == res_exp + 1
         assert_eq_bignum!(
-            BigNum::from(0xFFFF_FFFF_FFFF_FFFEu64) + 1u64,
-            BigNum::new_raw(((u64::MAX as u128 + 1) / 61u128) as u64, 1)
+            diff(min_exp as u64 - 1),
+            BigNum::from(61u64.pow(min_exp - 1))
         );
-        assert_eq_bignum!(BigNum::from(1u64) + 1u64, BigNum::new_raw(2, 0));
+        // adj == res_exp
+        assert_eq_bignum!(diff(min_exp as u64), BigNum::from(min_sig));
+        // adj == res_exp - 1
+        assert_eq_bignum!(diff(min_exp as u64 + 1), BigNum::new(min_sig, 1));
+        assert_eq_bignum!(diff(1), BigNum::from(61));
+        assert_eq_bignum!(diff(100), BigNum::new(min_sig, 100 - min_exp as u64));
+
+        // Same boundaries with a multi-digit difference, `61^2 + 5` has magnitude 2
+        let diff = |exp: u64| BigNum::new(max_sig, exp) - BigNum::new(max_sig - 61 * 61 - 5, exp);
+        let adj = min_exp as u64 - 2;
+        assert_eq_bignum!(diff(adj + 1), BigNum::new(61 * 61 + 5, adj + 1));
         assert_eq_bignum!(
-            //BigNum::new(max_sig, 10, BASE) + BigNum::new(1, 10, BASE),
-            BigNum::new(max_sig, 10) + BigNum::new(1, 10),
-            BigNum::new_raw(min_sig, 11)
+            diff(adj),
+            BigNum::from((61 * 61 + 5) * 61u64.pow(adj as u32))
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 10) + BigNum::new(61u64, 9),
-            BigNum::new_raw(min_sig, 11)
+            diff(adj - 1),
+            BigNum::from((61 * 61 + 5) * 61u64.pow(adj as u32 - 1))
         );
+
+        for exp in 0..30 {
+            let (a, b) = (BigNum::new(max_sig, exp), BigNum::new(max_sig - 12345, exp));
+            assert_eq_bignum!(a - b, a.lossless_sub(b));
+            assert_eq_bignum!(a - b, BigNum::new(12345, exp));
+        }
     }
 
     #[test]
-    fn sub_binary_test() {
-        type BigNum = BigNumBase<Binary>;
+    fn new_zero_test() {
+        create_default_base!(Base61, 61);
+
+        for exp in [0, 1, 12345, u64::MAX] {
+            assert_eq_bignum!(BigNumDec::new(0, exp), BigNumDec::from(0));
+            assert_eq_bignum!(BigNumBin::new(0, exp), BigNumBin::from(0));
+            assert_eq_bignum!(
+                BigNumBase::<Base61>::new(0, exp),
+                BigNumBase::<Base61>::from(0)
+            );
+            assert_eq!(BigNumDec::new(0, exp).exp, 0);
+        }
 
         assert_eq_bignum!(
-            BigNum::new(0x100, 32) - BigNum::new(0x0080_0000_0000, 0),
-            BigNum::new_raw(0x0080_0000_0000, 0)
+            BigNumDec::new(12345, 50).rescale(100).normalize(),
+            BigNumDec::from(0)
         );
-        assert_eq_bignum!(
-            BigNum::new(0x1000_0000_0000_0000, 0) - BigNum::new(0x0010_0000_0000_0000, 8),
-            BigNum::from(0)
+    }
+
+    #[test]
+    fn checked_new_test() {
+        let (min_sig, max_sig) = DEC_SIG_RANGE;
+
+        assert_eq!(BigNumDec::checked_new(12345, 0), Ok(BigNumDec::from(12345)));
+        assert_eq!(
+            BigNumDec::checked_new(12345, 10),
+            Ok(BigNumDec::new(12345, 10))
         );
-        assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 48) - BigNum::new(0x8000_0000_0000_0000, 16),
-            BigNum::new(0xFFFF_FFFF_7FFF_FFFF, 48)
+        assert_eq!(
+            BigNumDec::checked_new(u64::MAX, 10),
+            Ok(BigNumDec::new(u64::MAX, 10))
+        );
+        assert_eq!(BigNumDec::checked_new(0, 10), Ok(BigNumDec::from(0)));
+        assert_eq!(
+            BigNumDec::checked_new(max_sig, u64::MAX),
+            Ok(BigNumDec::max_value())
+        );
+        assert_eq!(
+            BigNumDec::checked_new(u64::MAX, u64::MAX),
+            Err(BigNumError::ExpOverflow)
+        );
+        assert_eq!(
+            BigNumDec::from_parts(min_sig - 1, 1),
+            Err(BigNumError::InvalidSignificand {
+                sig: min_sig - 1,
+                exp: 1
+            })
+        );
+        assert_eq!(
+            BigNumBin::checked_new(1, u64::MAX),
+            Ok(BigNumBin::new_raw(1 << 63, u64::MAX - 63))
+        );
+
+        // Exponents past `u32::MAX` used to be truncated when checking for compact results
+        let huge = 1 << 32;
+        assert_eq!(
+            BigNumDec::checked_new(5, huge),
+            Ok(BigNumDec::new_raw(5 * 10u64.pow(18), huge - 18))
+        );
+    }
+
+    #[test]
+    fn bignum_error_test() {
+        #[derive(Clone, Copy, Debug)]
+        struct Unary;
+
+        impl Base for Unary {
+            const NUMBER: u32 = 1;
+
+            fn new() -> Self {
+                Self
+            }
+
+            fn exp_range(&self) -> ExpRange {
+                ExpRange(0, 0)
+            }
+
+            fn sig_range(&self) -> SigRange {
+                SigRange(0, 0)
+            }
+        }
+
+        assert_eq!(
+            BigNumDec::max_value().try_mul(BigNumDec::from(10)),
+            Err(BigNumError::ExpOverflow)
+        );
+        assert_eq!(
+            BigNumDec::max_value().try_shl(1),
+            Err(BigNumError::ExpOverflow)
+        );
+        assert_eq!(
+            BigNumDec::from(5).try_shr(3),
+            Err(BigNumError::ShiftUnderflow)
+        );
+        assert_eq!(
+            BigNumDec::from(5).try_sub(BigNumDec::from(6)),
+            Err(BigNumError::SubUnderflow)
+        );
+        assert_eq!(
+            Unary::try_calculate_ranges(),
+            Err(BigNumError::InvalidBase(1))
         );
+        // `MulNormalization` can't be produced by a valid base, see the variant docs
+
+        assert_eq!(
+            BigNumDec::from(6).try_sub(BigNumDec::from(5)),
+            Ok(BigNumDec::from(1))
+        );
+        assert_eq!(BigNumDec::from(500).try_shr(2), Ok(BigNumDec::from(5)));
+    }
+
+    #[test]
+    fn float32_mult_test() {
+        type BigNum = BigNumDec;
+
+        let a = BigNum::new(DEC_SIG_RANGE.0, 1234);
+        let b = BigNum::new(DEC_SIG_RANGE.1, 1234);
+
+        assert_eq!(a.mul_f32(1.5), a * 3 / 2);
+        assert_eq!(a.mul_f32(12.5), a * 100 / 8);
+        assert_eq!(b.mul_f32(0.5), b / 2);
+        assert_eq!(b.mul_f32(1.234e30), b * 1.234e30f32 as f64);
+
+        let mut c = a;
+        c.mul_assign_f32(1.5);
+        assert_eq!(c, a * 1.5);
+
+        assert_eq_bignum!(BigNum::from(5).mul_f32(f32::INFINITY), BigNum::max_value());
+        assert_eq_bignum!(BigNum::from(5).mul_f32(-2.0), BigNum::from(0));
+        assert_eq_bignum!(BigNum::from(0).mul_f32(f32::INFINITY), BigNum::from(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn float32_mult_nan_test() {
+        let _ = BigNumDec::from(5).mul_f32(f32::NAN);
+    }
+
+    #[test]
+    fn pow_assign_test() {
+        for x in [
+            BigNumDec::from(7),
+            BigNumDec::new(DEC_SIG_RANGE.1, 1234),
+            BigNumDec::from(0),
+        ] {
+            let mut y = x;
+            y.pow_assign(3);
+            assert_eq_bignum!(y, x.pow(3));
+        }
+    }
+
+    #[test]
+    fn isqrt_test() {
+        assert_eq_bignum!(BigNumDec::from(80).isqrt(), BigNumDec::from(8));
+        assert_eq_bignum!(BigNumDec::from(81).isqrt(), BigNumDec::from(9));
+        assert_eq_bignum!(BigNumDec::from(0).isqrt(), BigNumDec::from(0));
+        assert_eq_bignum!(BigNumDec::new(1, 100).isqrt(), BigNumDec::new(1, 50));
         assert_eq_bignum!(
-            BigNum::new(0xFFFF_FFFF_FFFF_FFFF, 48) - BigNum::new(0xFFFF_FFFF_0000_0000, 48),
-            BigNum::new(0xFFFF_FFFF_0000_0000, 16)
+            BigNumDec::new(1, 101).isqrt(),
+            BigNumDec::new(3162277660168379331, 32)
         );
+        assert_eq_bignum!(BigNumBin::new(1, 1000).isqrt(), BigNumBin::new(1, 500));
         assert_eq_bignum!(
-            BigNum::new(0x8000_0000_0000_0000, 48) - BigNum::new(0xFFFF_FFFF_0000_0000, 16),
-            BigNum::new(0xFFFF_FFFE_0000_0002, 47)
+            BigNumBin::max_value().isqrt(),
+            BigNumBin::new(13043817825332782211, u64::MAX / 2 - 31)
         );
+
+        let x = BigNumDec::new(DEC_SIG_RANGE.1, 1234);
+        assert!((x.isqrt() * x.isqrt()).relative_error(x) < 1e-17);
+
+        let mut y = x;
+        y.isqrt_assign();
+        assert_eq_bignum!(y, x.isqrt());
     }
 
-    // I won't test each individual base since the logic is the same, but I will test
-    // binary and arbitrary
     #[test]
-    fn sub_arbitrary_test() {
-        create_default_base!(Base61, 61);
-        type BigNum = BigNumBase<Base61>;
-
-        let SigRange(min_sig, max_sig) = Base61::calculate_ranges().1;
+    fn geometric_mean_test() {
+        let mean = BigNumDec::geometric_mean([2, 8].into_iter().map(BigNumDec::from));
+        assert!(mean.fuzzy_eq(BigNumDec::from(4), 0));
+
+        let mean = BigNumDec::geometric_mean([1, 10, 100].into_iter().map(BigNumDec::from));
+        assert!(mean.fuzzy_eq(BigNumDec::from(10), 0));
+
+        let a = BigNumDec::new(2, 1000);
+        let b = BigNumDec::new(8, 3000);
+        assert!(
+            BigNumDec::geometric_mean([a, b].into_iter()).relative_error(BigNumDec::new(4, 2000))
+                < 1e-14
+        );
 
-        // This is an example of how subtraction results in a loss of precision. I may
-        // do a lossless_sub trait at some point that casts both sigs to u128 before
-        // calculating
+        let big = BigNumBin::max_value();
+        assert!(BigNumBin::geometric_mean([big; 3].into_iter()).relative_error(big) < 1e-14);
 
+        let x = BigNumDec::new(DEC_SIG_RANGE.1, 1234);
         assert_eq_bignum!(
-            BigNum::new(min_sig, 1) - 61u64,
-            BigNum::new_raw(max_sig - 60, 0)
+            BigNumDec::geometric_mean([x, BigNumDec::from(0)].into_iter()),
+            BigNumDec::from(0)
         );
+    }
+
+    #[test]
+    #[should_panic(expected = "empty iterator")]
+    fn geometric_mean_empty_test() {
+        BigNumDec::geometric_mean(std::iter::empty());
+    }
+
+    #[test]
+    fn nth_root_test() {
+        assert_eq_bignum!(BigNumDec::from(27).nth_root(3), BigNumDec::from(3));
+        assert_eq_bignum!(BigNumDec::from(26).nth_root(3), BigNumDec::from(2));
         assert_eq_bignum!(
-            BigNum::new(max_sig, 1) - max_sig,
-            BigNum::new_raw(max_sig - max_sig / 61, 1)
+            BigNumBin::from(u64::MAX).nth_root(2),
+            BigNumBin::from(u32::MAX as u64)
+        );
+        assert_eq_bignum!(BigNumDec::from(0).nth_root(5), BigNumDec::from(0));
+        assert_eq_bignum!(BigNumDec::from(12345).nth_root(1), BigNumDec::from(12345));
+        assert!(
+            BigNumDec::new(1, 300)
+                .nth_root(3)
+                .relative_error(BigNumDec::new(1, 100))
+                < 1e-17
         );
-        assert_eq_bignum!(
-            BigNum::new(12341098709128730491, 11234) - BigNum::new(12341098709128730491, 11234),
-            BigNum::from(0)
-        )
+        assert!(BigNumBin::max_value()
+            .nth_root(2)
+            .fuzzy_eq(BigNumBin::max_value().isqrt(), 2));
+
+        let dist = Uniform::new_inclusive(BigNumDec::from(1), BigNumDec::new(1, u64::MAX / 4));
+        for x in dist.sample_iter(&mut thread_rng()).take(1000) {
+            assert!(x.pow(3).nth_root(3).fuzzy_eq(x, 4), "{:?}", x);
+        }
+
+        let dist = Uniform::new_inclusive(BigNumDec::from(1), BigNumDec::new(1, 30));
+        for x in dist.sample_iter(&mut thread_rng()).take(1000) {
+            assert!(x.pow(5).nth_root(5).fuzzy_eq(x, 4), "{:?}", x);
+        }
+        assert_eq_bignum!(BigNumDec::new(1, 30).nth_root(3), BigNumDec::new(1, 10));
+
+        // Expanded inputs with small roots
+        let x = BigNumDec::new(1, 100);
+        assert_eq_bignum!(x.nth_root(90), BigNumDec::from(12));
+        assert_eq_bignum!(x.nth_root(100), BigNumDec::from(10));
+        assert_eq_bignum!(x.nth_root(150), BigNumDec::from(4));
+        assert_eq_bignum!(x.nth_root(200), BigNumDec::from(3));
+        assert_eq_bignum!(x.nth_root(1000), BigNumDec::from(1));
+        assert_eq_bignum!(BigNumBin::new(1, 1000).nth_root(100), BigNumBin::from(1024));
+        assert_eq_bignum!(BigNumBin::new(1, 1000).nth_root(300), BigNumBin::from(10));
+
+        let dist = Uniform::new_inclusive(BigNumDec::new(1, 20), BigNumDec::new(1, 1000));
+        for (x, n) in dist.sample_iter(&mut thread_rng()).zip(100..1100) {
+            let r = x.nth_root(n);
+            assert!(r.pow(n) <= x, "{:?} {}", x, n);
+            assert!(
+                (r + 1).checked_pow(n).is_none_or(|p| p > x),
+                "{:?} {}",
+                x,
+                n
+            );
+        }
     }
 
     #[test]
-    fn mul_binary_test() {
-        type BigNum = BigNumBase<Binary>;
-        let SigRange(min_sig, max_sig) = Binary::calculate_ranges().1;
+    #[should_panic(expected = "0th root")]
+    fn nth_root_zero_test() {
+        BigNumDec::from(5).nth_root(0);
+    }
 
+    #[test]
+    fn round_to_significant_test() {
         assert_eq_bignum!(
-            BigNum::from(14215125) * BigNum::from(120487091724u64),
-            BigNum::from(120487091724u64 * 14215125)
+            BigNumDec::from(123456).round_to_significant(3),
+            BigNumDec::from(123000)
         );
-        // 2^63 * 2^63 = 2^126
         assert_eq_bignum!(
-            BigNum::from(min_sig) * BigNum::from(min_sig),
-            BigNum::new(min_sig, 63)
+            BigNumDec::from(123556).round_to_significant(3),
+            BigNumDec::from(124000)
         );
         assert_eq_bignum!(
-            BigNum::from(min_sig) * BigNum::from(min_sig),
-            BigNum::new(min_sig, 63)
+            BigNumDec::from(123500).round_to_significant(3),
+            BigNumDec::from(124000)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 1) * BigNum::new(max_sig, 1),
-            BigNum::new(max_sig - 1, 64 + 2)
+            BigNumDec::from(99950).round_to_significant(3),
+            BigNumDec::from(100000)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 1123) * BigNum::new(max_sig, 11325),
-            BigNum::new(max_sig - 1, 64 + 1123 + 11325)
+            BigNumDec::from(42).round_to_significant(3),
+            BigNumDec::from(42)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig - min_sig, 123410923) * BigNum::from(0),
-            BigNum::from(0)
+            BigNumDec::from(0).round_to_significant(3),
+            BigNumDec::from(0)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig - min_sig, 123410923) * BigNum::from(1),
-            BigNum::new(max_sig - min_sig, 123410923)
+            BigNumBin::from(0b1011).round_to_significant(2),
+            BigNumBin::from(0b1100)
         );
-    }
-
-    #[test]
-    fn binary_div_test() {
-        type BigNum = BigNumBase<Binary>;
-        let SigRange(min_sig, max_sig) = Binary::calculate_ranges().1;
 
         assert_eq_bignum!(
-            BigNum::from(123412341234432u64) / BigNum::from(1221314),
-            BigNum::from(123412341234432u64 / 1221314)
-        );
-        assert_eq_bignum!(
-            BigNum::from(123412341234432u64) / BigNum::from(123412341234432u64),
-            BigNum::from(1)
+            BigNumDec::new(1234567890123456789, 100).round_to_significant(4),
+            BigNumDec::new(1235000000000000000, 100)
         );
         assert_eq_bignum!(
-            BigNum::from(123412341234432u64) / BigNum::from(12341234123412341234u64),
-            BigNum::from(0)
+            BigNumDec::new(DEC_SIG_RANGE.1, 100).round_to_significant(5),
+            BigNumDec::new(DEC_SIG_RANGE.0, 101)
         );
         assert_eq_bignum!(
-            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234432u64, 12341234),
-            BigNum::from(1)
+            BigNumDec::max_value().round_to_significant(5),
+            BigNumDec::new(9999900000000000000, u64::MAX)
         );
+    }
+
+    #[test]
+    fn snap_to_power_test() {
         assert_eq_bignum!(
-            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234432u64, 12341235),
-            BigNum::from(0)
+            BigNumDec::from(7500).floor_to_power(),
+            BigNumDec::from(1000)
         );
         assert_eq_bignum!(
-            BigNum::new(123412341234432u64, 12341234) / BigNum::new(123412341234433u64, 12341234),
-            BigNum::from(0)
+            BigNumDec::from(7500).ceil_to_power(),
+            BigNumDec::from(10000)
         );
         assert_eq_bignum!(
-            BigNum::new(min_sig, 12341234) / BigNum::new(min_sig, 12341233),
-            BigNum::from(2)
+            BigNumDec::from(1000).floor_to_power(),
+            BigNumDec::from(1000)
         );
+        assert_eq_bignum!(BigNumDec::from(1000).ceil_to_power(), BigNumDec::from(1000));
+        assert_eq_bignum!(BigNumDec::from(0).floor_to_power(), BigNumDec::from(0));
+        assert_eq_bignum!(BigNumDec::from(0).ceil_to_power(), BigNumDec::from(1));
+        assert_eq_bignum!(BigNumBin::from(100).floor_to_power(), BigNumBin::from(64));
+        assert_eq_bignum!(BigNumBin::from(100).ceil_to_power(), BigNumBin::from(128));
+
+        // Crossing from compact to expanded
         assert_eq_bignum!(
-            BigNum::new(min_sig, 12341234) / BigNum::new(min_sig, 1),
-            BigNum::new(min_sig, 12341234 - 64)
+            BigNumDec::from(DEC_SIG_RANGE.1).ceil_to_power(),
+            BigNumDec::power_of_base(19)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 12341234) / BigNum::new(max_sig, 1),
-            BigNum::new(min_sig, 12341234 - 64)
+            BigNumDec::new(1234, 50).floor_to_power(),
+            BigNumDec::power_of_base(53)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 12341234) / BigNum::new(min_sig, 1),
-            BigNum::new(max_sig, 12341234 - 64)
+            BigNumDec::new(1234, 50).ceil_to_power(),
+            BigNumDec::power_of_base(54)
         );
         assert_eq_bignum!(
-            BigNum::new(max_sig, 63 + 12341234) / BigNum::new(min_sig, 1),
-            BigNum::new(max_sig, 12341234 - 1)
+            BigNumDec::max_value().floor_to_power(),
+            BigNumDec::new(DEC_SIG_RANGE.0, u64::MAX)
         );
     }
 
     #[test]
-    fn binary_shifts() {
-        type BigNum = BigNumBase<Binary>;
+    #[should_panic(expected = "Exponent overflow")]
+    fn ceil_to_power_overflow_test() {
+        BigNumDec::max_value().ceil_to_power();
+    }
 
-        assert_eq_bignum!(BigNum::new(0b100, 0) << 1, BigNum::new(0b1000, 0));
-        assert_eq_bignum!(BigNum::new(0b100, 0) << 2, BigNum::new(0b10000, 0));
-        assert_eq_bignum!(BigNum::new(u64::MAX, 1) << 3, BigNum::new(u64::MAX, 4));
-        assert_eq_bignum!(BigNum::new(u64::MAX, 0) << 64, BigNum::new(u64::MAX, 64));
+    #[test]
+    fn scale_by_permille_test() {
+        for x in [
+            BigNumDec::from(1234),
+            BigNumDec::new(DEC_SIG_RANGE.0, 1234),
+            BigNumDec::new(DEC_SIG_RANGE.1, 1234),
+        ] {
+            assert!(x.scale_by_permille(1500).fuzzy_eq(x * 3 / 2, 2));
+            assert!(x.scale_by_permille(25).fuzzy_eq(x / 40, 2));
+            assert_eq_bignum!(x.scale_by_permille(1000), x);
+            assert_eq_bignum!(x.scale_by_permille(0), BigNumDec::from(0));
+        }
 
-        assert_eq_bignum!(BigNum::new(0b100, 0) >> 1, BigNum::new(0b10, 0));
-        assert_eq_bignum!(BigNum::new(0b100, 0) >> 2, BigNum::new(0b1, 0));
-        assert_eq_bignum!(BigNum::new(u64::MAX, 1) >> 3, BigNum::new(u64::MAX / 4, 0));
-        assert_eq_bignum!(BigNum::new(u64::MAX, 0) >> 63, BigNum::from(1));
         assert_eq_bignum!(
-            BigNum::new(u64::MAX, 100) >> 105,
-            BigNum::new(u64::MAX / 32, 0)
+            BigNumDec::from(200).scale_by_permille(1075),
+            BigNumDec::from(215)
+        );
+        assert_eq_bignum!(
+            BigNumDec::from(3).scale_by_permille(500),
+            BigNumDec::from(1)
         );
     }
 
     #[test]
-    fn display_test() {
-        type BigNum = BigNumBase<Decimal>;
+    fn div_by_zero_test() {
+        for x in [
+            BigNumDec::from(0),
+            BigNumDec::from(5),
+            BigNumDec::new(DEC_SIG_RANGE.1, 1234),
+        ] {
+            assert_eq!(x.checked_div(BigNumDec::from(0)), None);
+            assert_eq!(
+                x.try_div(BigNumDec::from(0)),
+                Err(BigNumError::DivisionByZero)
+            );
+        }
 
-        assert_eq!(format!("{}", BigNum::from(1)), "1");
-        assert_eq!(format!("{}", BigNum::from(999)), "999");
-        assert_eq!(format!("{}", BigNum::from(1000)), "1k");
-        assert_eq!(format!("{}", BigNum::from(1001)), "1.001k");
-        assert_eq!(format!("{}", BigNum::from(999999)), "999.9k");
-        assert_eq!(format!("{}", BigNum::from(1000000)), "1m");
-        assert_eq!(format!("{}", BigNum::from(1001000)), "1.001m");
-        assert_eq!(format!("{}", BigNum::from(999999999)), "999.9m");
-        assert_eq!(format!("{}", BigNum::from(1000000000)), "1b");
-        assert_eq!(format!("{}", BigNum::from(1001000000)), "1.001b");
-        assert_eq!(format!("{}", BigNum::from(999999999999)), "999.9b");
-        assert_eq!(format!("{}", BigNum::from(1000000000000)), "1t");
-        assert_eq!(format!("{}", BigNum::from(1001000000000)), "1.001t");
-        assert_eq!(format!("{}", BigNum::from(999999999999999)), "999.9t");
-        assert_eq!(format!("{}", BigNum::from(1000000000000000)), "1e15");
-        assert_eq!(format!("{}", BigNum::from(1001000000000000)), "1.001e15");
-        assert_eq!(format!("{}", BigNum::from(999999999999999999)), "9.999e17");
-        assert_eq!(format!("{}", BigNum::new(9999, 123523)), "9.999e123526");
-        assert_eq!(format!("{}", BigNum::new(9099, 123523)), "9.099e123526");
-        assert_eq!(format!("{}", BigNum::new(999, 123523)), "9.99e123525");
+        assert_eq!(
+            BigNumDec::from(10).checked_div(BigNumDec::from(5)),
+            Some(BigNumDec::from(2))
+        );
+        assert_eq!(
+            BigNumDec::from(0).checked_div(BigNumDec::from(5)),
+            Some(BigNumDec::from(0))
+        );
     }
 
     #[test]
-    fn test_random_bin() {
-        #![allow(clippy::erasing_op)]
-
-        type BigNum = BigNumBase<Binary>;
-
-        let dist: Uniform<BigNum> = Uniform::new(BigNum::from(0), BigNum::new(1, u64::MAX / 2));
-        let rng = &mut thread_rng();
-        let nums = dist.sample_iter(rng).take(100);
-
-        for n in nums {
-            assert_eq_bignum!(n + n, n * 2);
-            assert_eq_bignum!(n + n + n, n * BigNum::new(3, 0));
-            assert_eq_bignum!(2 * n + 2 * n, 4 * n);
-            assert_eq_bignum!(n / 2 / 16, n / 32);
-            assert_eq_bignum!(n * 0, n / (n.succ()));
-            assert_eq_bignum!(n + 0, n / 1);
-            assert_eq_bignum!(n / n, BigNum::from(1));
-        }
+    #[should_panic(expected = "division by zero")]
+    fn div_by_zero_panic_test() {
+        let _ = BigNumDec::new(DEC_SIG_RANGE.1, 1234) / BigNumDec::from(0);
     }
 
     #[test]
-    fn sum_test_binary() {
-        type BigNum = BigNumBin;
-
-        let a: [BigNum; 0] = [];
-        let b: [BigNum; 10] = [BigNum::from(100); 10];
-        let c = (0u64..100).map(BigNum::from);
-        let d: [BigNum; 100] = [BigNum::from(1 << 63); 100];
-
-        assert_eq!(BigNum::from(0), a.into_iter().sum());
-        assert_eq!(BigNum::from(1000), b.into_iter().sum());
-        assert_eq!(BigNum::from(4950), c.sum());
-
-        assert_eq!(BigNum::from(1 << 63) * 100, d.into_iter().sum());
+    #[should_panic(expected = "division by zero")]
+    fn div_by_zero_u64_panic_test() {
+        let _ = BigNumDec::from(5) / 0;
     }
 
     #[test]
-    fn prod_test_binary() {
-        type BigNum = BigNumBin;
-
-        let a: [BigNum; 0] = [];
-        let b: [BigNum; 10] = [BigNum::from(2); 10];
-        let c: [BigNum; 10] = [BigNum::from(8); 10];
-        let d: [BigNum; 100] = [BigNum::from(1 << 63); 100];
-
-        assert_eq!(BigNum::from(0), a.into_iter().product());
-        assert_eq!(BigNum::from(1024), b.into_iter().product());
-        assert_eq!(BigNum::from(1024 * 1024 * 1024), c.into_iter().product());
+    fn debug_test() {
+        assert_eq!(
+            format!("{:?}", BigNumDec::new(15, 20)),
+            "BigNumBase<10>(sig=1500000000000000000, exp=3, ≈1.5e21)"
+        );
+        assert_eq!(
+            format!("{:?}", BigNumDec::from(0)),
+            "BigNumBase<10>(sig=0, exp=0, ≈0e0)"
+        );
 
-        assert_eq!(BigNum::new(1, 63 * 100), d.into_iter().product());
+        // Other bases spell out the radix of the approximation
+        assert_eq!(
+            format!("{:?}", BigNumBin::new(3 << 62, 19)),
+            "BigNumBase<2>(sig=13835058055282163712, exp=19, ≈1.5×2^82)"
+        );
+        assert_eq!(
+            format!("{:?}", BigNumHex::from(0x180)),
+            "BigNumBase<16>(sig=384, exp=0, ≈1.5×16^2)"
+        );
     }
 
-    #[should_panic]
     #[test]
-    fn fuzzy_eq_failed1() {
-        type BigNum = BigNumDec;
+    fn convert_test() {
+        assert_eq_bignum!(
+            BigNumDec::from(1000).convert::<Binary>(),
+            BigNumBin::from(1000)
+        );
+        assert_eq_bignum!(BigNumBin::from(0).convert::<Decimal>(), BigNumDec::from(0));
+        assert_eq_bignum!(
+            BigNumBin::new(1, 100).convert::<Decimal>(),
+            BigNumDec::from_u128(1 << 100)
+        );
+        assert_eq_bignum!(
+            BigNumHex::new(1234, 5000).convert::<Hexadecimal>(),
+            BigNumHex::new(1234, 5000)
+        );
 
-        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
-        let b = a + a + a + a + a;
-        let c = 2 * a + 3 * a;
+        let x = BigNumDec::new(DEC_SIG_RANGE.1, 300);
+        assert!(x.convert::<Binary>().convert::<Decimal>().relative_error(x) < 1e-13);
+        assert!(
+            BigNumDec::new(1, 100)
+                .convert::<Binary>()
+                .relative_error(BigNumBin::new(1, 100).convert())
+                > 0.5
+        );
 
-        assert_eq!(b, c);
+        assert_eq_bignum!(
+            BigNumDec::max_value().convert::<Binary>(),
+            BigNumBin::max_value()
+        );
     }
 
-    #[should_panic]
     #[test]
-    fn fuzzy_eq_failed2() {
-        type BigNum = BigNumDec;
-
-        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
-        let d: BigNum = repeat_n(a, 20).sum();
-        let e = a * 20;
-
-        assert_eq!(d, e)
+    fn eq_across_test() {
+        assert!(BigNumBin::from(1000).eq_across(BigNumDec::from(1000), 0));
+        assert!(!BigNumBin::from(1000).eq_across(BigNumDec::from(1001), 0));
+        assert!(BigNumDec::from(1000).eq_across(BigNumBin::from(1001), 1));
+
+        let x = BigNumDec::new(12345, 100);
+        assert!(x.eq_across(x.convert::<Binary>(), 100_000));
+        assert!(!x.eq_across((x * 2).convert::<Binary>(), 10000));
     }
 
     #[test]
-    fn fuzzy_eq_test() {
-        type BigNum = BigNumDec;
-
-        let a = BigNum::new(DEC_SIG_RANGE.1, 234);
-        let b = a + a + a + a + a;
-        let c = 2 * a + 3 * a;
-        let d: BigNum = repeat_n(a, 20).sum();
-        let e = a * 20;
+    fn ulp_test() {
+        assert_eq_bignum!(BigNumDec::smallest_positive(), BigNumDec::from(1));
+        assert_eq_bignum!(BigNumDec::from(0).ulp(), BigNumDec::from(1));
+        assert_eq_bignum!(BigNumDec::from(12345).ulp(), BigNumDec::from(1));
+        assert_eq_bignum!(BigNumDec::new(12345, 20).ulp(), BigNumDec::from(1_000_000));
+        assert_eq_bignum!(
+            BigNumDec::new(12345, 50).ulp(),
+            BigNumDec::power_of_base(36)
+        );
+        assert_eq_bignum!(BigNumDec::new(12345, 51).ulp(), BigNumDec::new(10, 36));
 
-        // Since we apply 4 operations to b this is a good upper bound
-        assert!(b.fuzzy_eq(c, 4));
-        // Since we apply 20 operations to d this is a good upper bound
-        assert!(d.fuzzy_eq(e, 20));
+        let dist = Uniform::new_inclusive(BigNumDec::from(0), BigNumDec::max_value().pred());
+        for x in dist.sample_iter(&mut thread_rng()).take(1000) {
+            assert!(x + x.ulp() > x, "{:?}", x);
+        }
+        for x in [
+            BigNumDec::new(DEC_SIG_RANGE.1, 5),
+            BigNumDec::from(DEC_SIG_RANGE.1),
+        ] {
+            assert!(x + x.ulp() > x);
+        }
     }
 
     #[test]
-    fn float_mult_test() {
-        type BigNum = BigNumDec;
-
-        let a = BigNum::new(DEC_SIG_RANGE.0, 1234);
-        let b = BigNum::new(DEC_SIG_RANGE.1, 1234);
+    fn geometric_sum_test() {
+        let first = BigNumDec::new(DEC_SIG_RANGE.0 * 3, 10);
+
+        for ratio in [2, 3, 7, 10, 1234].map(BigNumDec::from) {
+            for n in 0..=15 {
+                let series: BigNumDec = utils::geometric_exact(first, ratio).take(n).sum();
+                let sum = BigNumDec::geometric_sum(first, ratio, n as u64);
+
+                assert!(
+                    sum.fuzzy_eq(series, 20),
+                    "{:?}, {}: {:?} vs {:?}",
+                    ratio,
+                    n,
+                    sum,
+                    series
+                );
+            }
+        }
 
-        assert_eq!(a * 1.5, a * 3 / 2);
-        assert_eq!(a * 12.5, a * 100 / 8);
-        assert_eq!(a * 0.5, a / 2);
+        assert_eq_bignum!(
+            BigNumDec::geometric_sum(BigNumDec::from(5), BigNumDec::from(1), 7),
+            BigNumDec::from(35)
+        );
+        assert_eq_bignum!(
+            BigNumDec::geometric_sum(BigNumDec::from(5), BigNumDec::from(0), 7),
+            BigNumDec::from(5)
+        );
+        assert_eq_bignum!(
+            BigNumDec::geometric_sum(BigNumDec::from(5), BigNumDec::from(2), 0),
+            BigNumDec::from(0)
+        );
+        assert_eq_bignum!(
+            BigNumDec::geometric_sum(BigNumDec::from(1), BigNumDec::from(2), 10),
+            BigNumDec::from(1023)
+        );
+        assert_eq_bignum!(
+            BigNumDec::geometric_sum(BigNumDec::from(3), BigNumDec::from(10), 4),
+            BigNumDec::from(3333)
+        );
 
-        // Result of adding a to a float that doesn't fit in u64 bounds
-        let a_overflow_res = a * 1e250;
-        let a_exp_res = a * BigNum::new(1, 250);
-        let (min, max) = if a_overflow_res > a_exp_res {
-            (a_exp_res, a_overflow_res)
-        } else {
-            (a_overflow_res, a_exp_res)
-        };
+        // More terms than fit in a u32
+        assert_eq_bignum!(
+            BigNumBin::geometric_sum(BigNumBin::from(1), BigNumBin::from(2), 1 << 32),
+            BigNumBin::power_of_base(1 << 32)
+        );
+        let n = 5_000_000_000;
+        assert!(
+            BigNumDec::geometric_sum(BigNumDec::from(1), BigNumDec::from(10), n)
+                .fuzzy_eq(BigNumDec::new(1111111111111111111, n - 19), 2)
+        );
+    }
 
-        // Error in result is less than 1/100000 = .001%
-        assert!(max / (max - min) > BigNum::from(100000));
+    #[test]
+    #[should_panic(expected = "Exponent overflow")]
+    fn geometric_sum_overflow_test() {
+        BigNumBin::geometric_sum(BigNumBin::from(1), BigNumBin::from(4), u64::MAX);
+    }
 
-        assert_eq!(b * 1.5, b * 3 / 2);
-        assert_eq!(b * 12.5, b * 100 / 8);
-        assert_eq!(b * 0.5, b / 2);
+    #[test]
+    fn try_from_parts_exact_test() {
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(10u64.pow(19), 5),
+            Ok(BigNumDec::new(DEC_SIG_RANGE.0, 6))
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(10u64.pow(19) + 1, 5),
+            Err(BigNumError::InexactNormalization {
+                sig: 10u64.pow(19) + 1,
+                exp: 5
+            })
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(1234, 50),
+            Ok(BigNumDec::new(1234, 50))
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(1234, 5),
+            Ok(BigNumDec::from(123400000))
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(u64::MAX, 0),
+            Err(BigNumError::InexactNormalization {
+                sig: u64::MAX,
+                exp: 0
+            })
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(0, 5),
+            Ok(BigNumDec::from(0))
+        );
+        assert_eq!(
+            BigNumDec::try_from_parts_exact(10u64.pow(19), u64::MAX),
+            Err(BigNumError::ExpOverflow)
+        );
+        assert_eq!(
+            BigNumBin::try_from_parts_exact(u64::MAX, 0),
+            Ok(BigNumBin::from(u64::MAX))
+        );
+    }
 
-        let b_overflow_res = b * 1.234e280;
-        let b_exp_res = b * BigNum::new(1234, 277);
-        let (min, max) = if b_overflow_res > b_exp_res {
-            (b_exp_res, b_overflow_res)
-        } else {
-            (b_overflow_res, b_exp_res)
-        };
+    #[test]
+    fn in_range_test() {
+        let (lo, hi) = (BigNumDec::from(10), BigNumDec::new(DEC_SIG_RANGE.0, 5));
+
+        for (x, inclusive, exclusive) in [
+            (BigNumDec::from(9), false, false),
+            (lo, true, false),
+            (BigNumDec::from(11), true, true),
+            (hi.pred(), true, true),
+            (hi, true, false),
+            (hi.succ(), false, false),
+        ] {
+            assert_eq!(x.in_range(lo, hi), inclusive, "{:?}", x);
+            assert_eq!(x.in_range_exclusive(lo, hi), exclusive, "{:?}", x);
+            assert_eq!((lo..=hi).contains(&x), inclusive, "{:?}", x);
+        }
 
-        // Error in result is less than 1/100000 = .001%
-        assert!(max / (max - min) > BigNum::from(100000));
+        assert!(lo.in_range(lo, lo));
+        assert!(!lo.in_range_exclusive(lo, lo));
+        assert!(!BigNumDec::from(11).in_range(hi, lo));
+        assert!(!BigNumDec::from(11).in_range_exclusive(hi, lo));
     }
 }
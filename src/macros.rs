@@ -541,7 +541,51 @@ macro_rules! impl_for_types {
 }
 impl_for_types!(u64);
 
-/// This macro creates a default `Base` implementation with a given name and number.
+// Since `BigNumBase` is `Copy` these just dereference and delegate to the by-value impls,
+// the same way std does for the integer types
+macro_rules! impl_ref_ops {
+    ($(($trait:ident, $method:ident)),+) => {
+        $(
+            impl<'a, T> std::ops::$trait<&'a $crate::BigNumBase<T>> for &'a $crate::BigNumBase<T>
+            where
+                T: $crate::Base,
+            {
+                type Output = $crate::BigNumBase<T>;
+
+                fn $method(self, rhs: &'a $crate::BigNumBase<T>) -> Self::Output {
+                    std::ops::$trait::$method(*self, *rhs)
+                }
+            }
+
+            impl<'a, T> std::ops::$trait<&'a $crate::BigNumBase<T>> for $crate::BigNumBase<T>
+            where
+                T: $crate::Base,
+            {
+                type Output = $crate::BigNumBase<T>;
+
+                fn $method(self, rhs: &'a $crate::BigNumBase<T>) -> Self::Output {
+                    std::ops::$trait::$method(self, *rhs)
+                }
+            }
+
+            impl<T> std::ops::$trait<$crate::BigNumBase<T>> for &$crate::BigNumBase<T>
+            where
+                T: $crate::Base,
+            {
+                type Output = $crate::BigNumBase<T>;
+
+                fn $method(self, rhs: $crate::BigNumBase<T>) -> Self::Output {
+                    std::ops::$trait::$method(*self, rhs)
+                }
+            }
+        )+
+    };
+}
+impl_ref_ops!((Add, add), (Sub, sub), (Mul, mul), (Div, div));
+
+/// This macro creates a default `Base` implementation with a given name and number. The
+/// ranges are computed with `Base::calculate_ranges` the first time the base is used and
+/// cached for the rest of the program.
 ///
 /// # Examples
 /// ```
@@ -554,20 +598,57 @@ impl_for_types!(u64);
 ///
 /// assert_eq!(bn1 >> 1, BigNum::from(1));
 /// ```
+///
+/// An optional third argument names a type alias for the `BigNumBase`, so the example
+/// above can be shortened to
+/// ```
+/// use bignumbe_rs::create_default_base;
+///
+/// create_default_base!(Base83, 83, BigNum);
+///
+/// assert_eq!(BigNum::from(83) >> 1, BigNum::from(1));
+/// ```
+///
+/// Bases below 2 are rejected at compile time
+/// ```compile_fail
+/// use bignumbe_rs::create_default_base;
+///
+/// create_default_base!(Unary, 1);
+/// ```
 #[macro_export]
 macro_rules! create_default_base {
+    ($name:ident, $num:literal, $alias:ident) => {
+        $crate::create_default_base!($name, $num);
+
+        pub type $alias = $crate::BigNumBase<$name>;
+    };
     ($name:ident, $num:literal) => {
+        const _: () = assert!($num >= 2, "Invalid base, bases must be at least 2");
+
         #[derive(Debug, Clone, Copy)]
         pub struct $name {
             pub exp_range: $crate::ExpRange,
             pub sig_range: $crate::SigRange,
         }
 
+        impl $name {
+            // `new` is called for every value created from scratch, so the ranges are only
+            // computed the first time
+            fn ranges_cache() -> &'static std::sync::OnceLock<($crate::ExpRange, $crate::SigRange)>
+            {
+                static RANGES: std::sync::OnceLock<($crate::ExpRange, $crate::SigRange)> =
+                    std::sync::OnceLock::new();
+
+                &RANGES
+            }
+        }
+
         impl $crate::Base for $name {
-            const NUMBER: u16 = $num;
+            const NUMBER: u32 = $num;
 
             fn new() -> Self {
-                let (exp_range, sig_range) = Self::calculate_ranges();
+                let (exp_range, sig_range) =
+                    *Self::ranges_cache().get_or_init(Self::calculate_ranges);
                 Self {
                     exp_range,
                     sig_range,
@@ -585,6 +666,29 @@ macro_rules! create_default_base {
     };
 }
 
+/// Defines several bases at once, expanding to one `create_default_base!` per entry.
+/// Like `create_default_base!`, each entry can also name a type alias for its
+/// `BigNumBase`. `macro_rules!` can't build an identifier like `BigNumBase61` from the
+/// number, so the alias has to be spelled out
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::create_default_bases;
+///
+/// create_default_bases!((Base7, 7, BigNum7), (Base61, 61, BigNum61), (Base83, 83));
+///
+/// assert_eq!(BigNum7::from(49) >> 2, BigNum7::from(1));
+/// assert_eq!(BigNum61::from(60) + 1, BigNum61::from(61));
+/// ```
+#[macro_export]
+macro_rules! create_default_bases {
+    ($(($name:ident, $num:literal $(, $alias:ident)?)),+ $(,)?) => {
+        $(
+            $crate::create_default_base!($name, $num $(, $alias)?);
+        )+
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -639,8 +743,8 @@ mod tests {
     #[test]
     #[cfg(feature = "macro")]
     fn test_many_efficient_bases() {
+        use crate::create_efficient_base;
         use crate::{Base, BigNumBase, Decimal, ExpRange, Octal, SigRange};
-        use bignum_proc_macro::create_efficient_base;
         create_efficient_base!(61);
         create_efficient_base!(11142);
         create_efficient_base!(942);
@@ -678,4 +782,104 @@ mod tests {
         test_base!(Octal);
         test_base!(Decimal);
     }
+
+    #[test]
+    fn default_base_alias_test() {
+        create_default_base!(Base7, 7, BigNum7);
+        create_default_base!(Base13, 13);
+
+        assert_eq!(
+            BigNum7::new(150, 2),
+            BigNumBase::<Base7>::new_raw(150 * 49, 0)
+        );
+        assert_eq!(BigNum7::from(48) + 1, BigNum7::power_of_base(2));
+        assert_eq!(
+            BigNumBase::<Base13>::from(169) >> 2,
+            BigNumBase::<Base13>::from(1)
+        );
+    }
+
+    #[test]
+    fn default_bases_test() {
+        use crate::{ExpRange, SigRange};
+
+        create_default_bases!((Base7, 7, BigNum7), (Base61, 61, BigNum61), (Base942, 942));
+
+        test_base!(Base7);
+        test_base!(Base61);
+        test_base!(Base942);
+
+        assert_eq!(BigNum7::from(48) + 1, BigNum7::power_of_base(2));
+        assert_eq!(
+            BigNum61::new(1, 100) * BigNum61::new(1, 50),
+            BigNum61::new(1, 150)
+        );
+        assert_eq!(
+            BigNumBase::<Base942>::from(942 * 942) / 942,
+            BigNumBase::<Base942>::from(942)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macro")]
+    fn make_bignum_test() {
+        use crate::{make_bignum, BigNumBin, BigNumDec, BigNumHex, Binary, Decimal, Hexadecimal};
+
+        const TOP: BigNumBin = make_bignum!(Binary, 1 << 63, 19);
+
+        assert_eq!(TOP, BigNumBin::new(1, 82));
+        assert_eq!(make_bignum!(Decimal, 1.5e40), BigNumDec::new(15, 39));
+        assert_eq!(make_bignum!(Decimal, 2.75), BigNumDec::from(2));
+        assert_eq!(make_bignum!(Decimal, 0.5e-3), BigNumDec::from(0));
+        assert_eq!(make_bignum!(Hexadecimal, 0xff), BigNumHex::from(255));
+        assert_eq!(
+            make_bignum!(Binary, 1e30),
+            BigNumBin::from_u128(10u128.pow(30))
+        );
+        assert_eq!(make_bignum!(Decimal, 12345, 0), BigNumDec::from(12345));
+
+        create_default_base!(Base61, 61);
+        assert_eq!(
+            make_bignum!(Base61, 3721),
+            BigNumBase::<Base61>::power_of_base(2)
+        );
+        assert_eq!(
+            make_bignum!(Base61, Base61::calculate_ranges().1 .0, 5),
+            BigNumBase::<Base61>::power_of_base(14)
+        );
+    }
+
+    #[test]
+    fn default_base_cached_ranges_test() {
+        create_default_base!(Base7, 7);
+        create_default_base!(Base13, 13);
+
+        assert!(Base7::ranges_cache().get().is_none());
+        let first = BigNumBase::<Base7>::from(1).base;
+        let cached = Base7::ranges_cache()
+            .get()
+            .expect("ranges should be cached by new");
+        assert_eq!(*cached, (first.exp_range(), first.sig_range()));
+        assert!(Base13::ranges_cache().get().is_none());
+
+        // Each base gets its own cache, so these shouldn't interfere with each other
+        for _ in 0..10_000 {
+            let (b7, b13) = (Base7::new(), Base13::new());
+
+            assert_eq!((b7.exp_range(), b7.sig_range()), Base7::calculate_ranges());
+            assert_eq!(
+                (b13.exp_range(), b13.sig_range()),
+                Base13::calculate_ranges()
+            );
+            assert!(std::ptr::eq(Base7::ranges_cache().get().unwrap(), cached));
+        }
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| BigNumBase::<Base13>::from(u64::MAX).base.sig_range()))
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Base13::calculate_ranges().1);
+        }
+    }
 }
@@ -1,19 +1,73 @@
 use std::cmp::Ordering;
 
 use rand::{
-    distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+        Distribution, Standard,
+    },
     Rng,
 };
 
-use crate::{Base, BigNumBase};
+use crate::{traits::Pred, Base, BigNumBase, SigRange};
 
+/// The `UniformSampler` backing `Uniform<BigNumBase<T>>`. Samples are uniform in value
+/// across the range, respecting the half-open `[low, high)` contract of `Uniform::new`.
+/// This means that for wide ranges nearly every sample has the same exponent as `high`,
+/// see `LogUniform` if you want small values to show up too
 pub struct BigNumSampler<T>
 where
     T: Base,
 {
-    low: BigNumBase<T>,
-    high: BigNumBase<T>,
-    inc: bool,
+    // The exponents with at least one value in the range, from highest to lowest, each
+    // with its inclusive range of significands and its relative chance of being picked
+    buckets: Vec<(u64, u64, u64, f64)>,
+    total_weight: f64,
+    base: T,
+}
+
+impl<T> BigNumSampler<T>
+where
+    T: Base,
+{
+    fn from_bounds(low: BigNumBase<T>, high: BigNumBase<T>, inc: bool) -> Self {
+        let base = high.base;
+        let SigRange(min_sig, max_sig) = base.sig_range();
+        let number = T::NUMBER as f64;
+
+        // Adjacent values with exponent `e` are `T::NUMBER^e` apart, so to be uniform in
+        // value each exponent's chance of being picked is proportional to the number of
+        // valid significands it has in the range times `T::NUMBER^e`. Anything more than
+        // 64 exponents below the top has a weight too small to matter
+        let lowest = low.exp.max(high.exp.saturating_sub(64));
+        let buckets: Vec<(u64, u64, u64, f64)> = (lowest..=high.exp)
+            .rev()
+            .filter_map(|exp| {
+                let low_sig = if exp == low.exp { low.sig } else { min_sig };
+                let high_sig = if exp != high.exp {
+                    max_sig
+                } else if inc {
+                    high.sig
+                } else {
+                    high.sig.checked_sub(1)?
+                };
+
+                if low_sig > high_sig || (exp != 0 && high_sig < min_sig) {
+                    return None;
+                }
+
+                let count = (high_sig - low_sig) as f64 + 1.0;
+                let weight = count * number.powi(-((high.exp - exp) as i32));
+
+                Some((exp, low_sig, high_sig, weight))
+            })
+            .collect();
+
+        Self {
+            total_weight: buckets.iter().map(|b| b.3).sum(),
+            buckets,
+            base,
+        }
+    }
 }
 
 impl<T> UniformSampler for BigNumSampler<T>
@@ -30,11 +84,7 @@ where
         let (low, high) = (*low.borrow(), *high.borrow());
 
         match low.cmp(&high) {
-            Ordering::Less => Self {
-                low,
-                high,
-                inc: false,
-            },
+            Ordering::Less => Self::from_bounds(low, high, false),
             Ordering::Greater => panic!("Unable to create non-inclusive range with low > high"),
             Ordering::Equal => panic!("Unable to create non-inclusive range with low == high"),
         }
@@ -51,55 +101,140 @@ where
             panic!("Unable to create non-inclusive range with low > high")
         }
 
-        Self {
-            low,
-            high,
-            inc: true,
-        }
+        Self::from_bounds(low, high, true)
     }
 
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
-        let (low_exp, high_exp) = (self.low.exp, self.high.exp);
-        let (low_sig, high_sig) = (self.low.sig, self.high.sig);
-        let base = self.high.base;
-        let sig_range = base.sig_range();
-
-        if low_exp == high_exp {
-            let sig_gen: UniformInt<u64> = if self.inc {
-                UniformInt::new_inclusive(low_sig, high_sig)
-            } else {
-                UniformInt::new(low_sig, high_sig)
-            };
-
-            return Self::X {
-                sig: sig_gen.sample(rng),
-                exp: high_exp,
-                base: self.high.base,
-            };
+        let mut target = rng.gen::<f64>() * self.total_weight;
+
+        let &(exp, low_sig, high_sig, _) = self
+            .buckets
+            .iter()
+            .find(|b| {
+                target -= b.3;
+                target < 0.0
+            })
+            .unwrap_or_else(|| self.buckets.last().unwrap());
+
+        let sig_gen = UniformInt::<u64>::new_inclusive(low_sig, high_sig);
+
+        Self::X {
+            sig: sig_gen.sample(rng),
+            exp,
+            base: self.base,
         }
+    }
+}
+
+impl<T> SampleUniform for BigNumBase<T>
+where
+    T: Base,
+{
+    type Sampler = BigNumSampler<T>;
+}
 
-        let exp_gen: UniformInt<u64> = if !self.inc && high_sig == sig_range.min() {
-            // Since the high value can't be included we must take a non-inclusive range
-            // for exp
-            UniformInt::new(low_exp, high_exp)
+/// Generates a random value across the whole representable range. The exponent is
+/// uniform over `[0, u64::MAX]`, so magnitudes are spread evenly in log scale rather than
+/// clustering near the top, and the significand is uniform over the valid range for that
+/// exponent. Mostly useful for quickly generating test data with `rng.gen()`
+impl<T> Distribution<BigNumBase<T>> for Standard
+where
+    T: Base,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigNumBase<T> {
+        let base = T::new();
+        let SigRange(min_sig, max_sig) = base.sig_range();
+
+        let exp: u64 = rng.gen();
+        let sig = if exp == 0 {
+            rng.gen_range(0..=max_sig)
         } else {
-            UniformInt::new_inclusive(low_exp, high_exp)
+            rng.gen_range(min_sig..=max_sig)
         };
 
-        let sig_gen: UniformInt<u64> = UniformInt::new_inclusive(0, sig_range.max());
+        BigNumBase { sig, exp, base }
+    }
+}
+
+/// A distribution over the inclusive range `[low, high]` that is uniform in the order of
+/// magnitude rather than in the value. I.e. each magnitude in the range is equally likely
+/// to be picked, and then a value is picked uniformly among those with that magnitude. The
+/// regular `Uniform` distribution almost always returns values near `high`, so this is
+/// better suited to property testing where small values matter as much as big ones.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{random::LogUniform, BigNumDec};
+/// use rand::{distributions::Distribution, thread_rng};
+///
+/// let dist = LogUniform::new(BigNumDec::from(1), BigNumDec::new(1, 100));
+/// let n = dist.sample(&mut thread_rng());
+///
+/// assert!(n >= BigNumDec::from(1) && n <= BigNumDec::new(1, 100));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LogUniform<T>
+where
+    T: Base,
+{
+    low: BigNumBase<T>,
+    high: BigNumBase<T>,
+}
 
-        let exp_sample = exp_gen.sample(rng);
-        let sig_sample = sig_gen.sample(rng);
+impl<T> LogUniform<T>
+where
+    T: Base,
+{
+    /// Creates a distribution over `[low, high]`, panicking if `low > high`
+    pub fn new(low: BigNumBase<T>, high: BigNumBase<T>) -> Self {
+        if low > high {
+            panic!("Unable to create LogUniform range with low > high")
+        }
 
-        Self::X::new(sig_sample, exp_sample)
+        Self { low, high }
     }
 }
 
-impl<T> SampleUniform for BigNumBase<T>
+impl<T> Distribution<BigNumBase<T>> for LogUniform<T>
 where
     T: Base,
 {
-    type Sampler = BigNumSampler<T>;
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigNumBase<T> {
+        let (low_mag, high_mag) = (self.low.magnitude(), self.high.magnitude());
+        let mag = rng.gen_range(low_mag..=high_mag);
+
+        // Every value with a given magnitude (short of the saturated top one) shares an
+        // exponent, so the sampler only has to pick a significand
+        let low = if mag == low_mag {
+            self.low
+        } else {
+            BigNumBase::power_of_base(mag)
+        };
+        let high = if mag == high_mag {
+            self.high
+        } else {
+            BigNumBase::power_of_base(mag + 1).pred()
+        };
+
+        BigNumSampler::new_inclusive(low, high).sample(rng)
+    }
+}
+
+/// Generates a value across the whole representable range using the given generator, so
+/// results are reproducible when it's seeded (e.g. `StdRng::seed_from_u64`). This uses the
+/// `Standard` distribution
+pub fn seeded_bignum<T: Base, R: Rng>(rng: &mut R) -> BigNumBase<T> {
+    rng.gen()
+}
+
+/// Generates a value in the half-open range `[low, high)` using the given generator, so
+/// results are reproducible when it's seeded. This uses the `Uniform` distribution
+pub fn seeded_bignum_between<T: Base, R: Rng>(
+    rng: &mut R,
+    low: BigNumBase<T>,
+    high: BigNumBase<T>,
+) -> BigNumBase<T> {
+    rng.gen_range(low..high)
 }
 
 #[cfg(test)]
@@ -109,9 +244,11 @@ mod tests {
         iter::from_fn,
     };
 
-    use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
+    use rand::{
+        distributions::Uniform, prelude::Distribution, rngs::StdRng, thread_rng, SeedableRng,
+    };
 
-    use crate::Binary;
+    use crate::{create_default_base, Binary, Decimal};
 
     use super::*;
 
@@ -219,25 +356,156 @@ mod tests {
         );
     }
 
-    // Since the implementation is not actually correct this test isn't useful for now
-    //#[test]
-    //fn rand_basic_test_2() {
-    //    type BigNum = BigNumBase<Binary>;
-    //
-    //    let (low, high) = (BigNum::from(u64::MAX), BigNum::new(1 << 63, 2));
-    //    let rng = &mut thread_rng();
-    //
-    //    let dist_inclusive: Uniform<BigNum> = Uniform::new_inclusive(low, high);
-    //    let dist_exclusive: Uniform<BigNum> = Uniform::new(low, high);
-    //
-    //    let inclusive_samples: Vec<BigNum> = from_fn(|| Some(dist_inclusive.sample(rng)))
-    //        .take(1000)
-    //        .collect();
-    //    let exclusive_samples: Vec<BigNum> = from_fn(|| Some(dist_exclusive.sample(rng)))
-    //        .take(1000)
-    //        .collect();
-    //
-    //    let inclusive_map = generate_freq_map!(exp, samples = inclusive_samples, range = 0..=2);
-    //    let exclusive_map = generate_freq_map!(exp, samples = exclusive_samples, range = 0..=2);
-    //}
+    #[test]
+    fn rand_exp_boundary_test() {
+        type BigNum = BigNumBase<Decimal>;
+
+        let SigRange(min_sig, max_sig) = Decimal::new().sig_range();
+
+        // 100 values with exp 0 and 10 with exp 1. Since the exp 1 values are 10 times as
+        // far apart, each exponent should cover half of the range
+        let (low, high) = (BigNum::from(max_sig - 99), BigNum::new(min_sig + 10, 1));
+        let dist_exclusive: Uniform<BigNum> = Uniform::new(low, high);
+        let dist_inclusive: Uniform<BigNum> = Uniform::new_inclusive(low, high);
+        let rng = &mut thread_rng();
+
+        let exclusive_samples: Vec<BigNum> = from_fn(|| Some(dist_exclusive.sample(rng)))
+            .take(2000)
+            .collect();
+
+        for n in exclusive_samples.iter() {
+            assert!(*n >= low && *n < high);
+        }
+
+        let exclusive_map = generate_freq_map!(exp, samples = exclusive_samples, range = 0..=1);
+        assert_uniform!(
+            map = exclusive_map,
+            range = 0..=1,
+            expected = 1000,
+            confidence = 150
+        );
+
+        // high is only reachable with the inclusive distribution
+        assert!(from_fn(|| Some(dist_inclusive.sample(rng)))
+            .take(10_000)
+            .any(|n| n == high));
+
+        // The only valid value in this range is low
+        let (low, high) = (BigNum::from(max_sig), BigNum::new(min_sig, 1));
+        let dist: Uniform<BigNum> = Uniform::new(low, high);
+        for _ in 0..1000 {
+            assert_eq!(dist.sample(rng), low);
+        }
+
+        let (low, high) = (BigNum::new(max_sig, 10), BigNum::new(min_sig, 11));
+        let dist: Uniform<BigNum> = Uniform::new(low, high);
+        for _ in 0..1000 {
+            assert_eq!(dist.sample(rng), low);
+        }
+    }
+
+    #[test]
+    fn rand_standard_test() {
+        create_default_base!(Base61, 61);
+
+        let rng = &mut thread_rng();
+
+        let bin: Vec<BigNumBase<Binary>> = from_fn(|| Some(rng.gen())).take(1000).collect();
+        let dec: Vec<BigNumBase<Decimal>> = from_fn(|| Some(rng.gen())).take(1000).collect();
+        let b61: Vec<BigNumBase<Base61>> = from_fn(|| Some(rng.gen())).take(1000).collect();
+
+        for n in bin {
+            assert!(BigNumBase::<Binary>::is_valid(
+                n.sig,
+                n.exp,
+                n.base.sig_range()
+            ));
+        }
+        for n in dec {
+            assert!(BigNumBase::<Decimal>::is_valid(
+                n.sig,
+                n.exp,
+                n.base.sig_range()
+            ));
+        }
+        for n in b61.iter() {
+            assert!(BigNumBase::<Base61>::is_valid(
+                n.sig,
+                n.exp,
+                n.base.sig_range()
+            ));
+        }
+
+        // The exponent is uniform over the whole u64 range, so about half of the samples
+        // should land in the top half
+        let high = b61.iter().filter(|n| n.exp > u64::MAX / 2).count();
+        assert!(high.abs_diff(500) < 100, "{} samples in the top half", high);
+    }
+
+    #[test]
+    fn log_uniform_test() {
+        type BigNum = BigNumBase<Decimal>;
+
+        let (low, high) = (BigNum::from(1), BigNum::new(1, 39));
+        let dist = LogUniform::new(low, high);
+        let rng = &mut thread_rng();
+
+        let samples: Vec<BigNum> = from_fn(|| Some(dist.sample(rng))).take(40_000).collect();
+
+        for n in samples.iter() {
+            assert!(*n >= low && *n <= high);
+        }
+
+        let mut map: HashMap<u64, u64> = HashMap::new();
+        for n in samples {
+            *map.entry(n.magnitude()).or_default() += 1;
+        }
+
+        assert_eq!(map.len(), 40);
+        assert_uniform!(map = map, range = 0..40, expected = 1000, confidence = 200);
+    }
+
+    #[test]
+    fn log_uniform_bounds_test() {
+        type BigNum = BigNumBase<Binary>;
+
+        let rng = &mut thread_rng();
+
+        let (low, high) = (BigNum::new(1 << 63, 10), BigNum::new(3 << 62, 10));
+        let dist = LogUniform::new(low, high);
+        for _ in 0..1000 {
+            let n = dist.sample(rng);
+            assert!(n >= low && n <= high);
+        }
+
+        let (low, high) = (BigNum::from(0), BigNum::max_value());
+        let dist = LogUniform::new(low, high);
+        for _ in 0..1000 {
+            let n = dist.sample(rng);
+            assert!(BigNum::is_valid(n.sig, n.exp, n.base.sig_range()));
+        }
+
+        let dist = LogUniform::new(high, high);
+        assert_eq!(dist.sample(rng), high);
+    }
+
+    #[test]
+    fn seeded_test() {
+        type BigNum = BigNumBase<Binary>;
+
+        let (low, high) = (BigNum::from(10), BigNum::new(1 << 63, 100));
+        let generate = |seed: u64| {
+            let rng = &mut StdRng::seed_from_u64(seed);
+            let mut seq: Vec<BigNum> = from_fn(|| Some(seeded_bignum(rng))).take(100).collect();
+
+            seq.extend(from_fn(|| Some(seeded_bignum_between(rng, low, high))).take(100));
+            seq
+        };
+
+        let (seq1, seq2, seq3) = (generate(1234), generate(1234), generate(4321));
+
+        assert_eq!(seq1, seq2);
+        assert_ne!(seq1, seq3);
+        assert!(seq1[100..].iter().all(|n| *n >= low && *n < high));
+    }
 }
@@ -0,0 +1,226 @@
+//! This module contains `SignedBigNum`, a wrapper around `BigNumBase` that can represent
+//! negative values.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Base, BigNumBase};
+
+/// A `BigNumBase` paired with a sign. All of the magnitude math is delegated to the
+/// unsigned operations, so precision is the same as for `BigNumBase`, but unlike
+/// `BigNumBase` subtraction never underflows. Zero is always stored as non-negative so
+/// `-0 == 0`.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{signed::SignedBigNum, BigNumDec};
+///
+/// let gain = SignedBigNum::from(BigNumDec::from(100));
+/// let loss = SignedBigNum::from(BigNumDec::from(250));
+///
+/// let delta = gain - loss;
+///
+/// assert!(delta.is_negative());
+/// assert_eq!(delta.magnitude(), BigNumDec::from(150));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SignedBigNum<T>
+where
+    T: Base,
+{
+    magnitude: BigNumBase<T>,
+    negative: bool,
+}
+
+impl<T> SignedBigNum<T>
+where
+    T: Base,
+{
+    /// Creates a signed value with the given magnitude, which is negative if `negative`
+    /// is true and the magnitude is non-zero
+    pub fn new(magnitude: BigNumBase<T>, negative: bool) -> Self {
+        Self {
+            magnitude,
+            negative: negative && magnitude.sig != 0,
+        }
+    }
+
+    /// Returns the absolute value as an unsigned `BigNumBase`
+    pub fn magnitude(self) -> BigNumBase<T> {
+        self.magnitude
+    }
+
+    /// Returns true if the value is less than 0
+    pub fn is_negative(self) -> bool {
+        self.negative
+    }
+}
+
+impl<T> PartialEq for SignedBigNum<T>
+where
+    T: Base,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.magnitude == other.magnitude && self.negative == other.negative
+    }
+}
+
+impl<T> Eq for SignedBigNum<T> where T: Base {}
+
+impl<T> From<BigNumBase<T>> for SignedBigNum<T>
+where
+    T: Base,
+{
+    fn from(value: BigNumBase<T>) -> Self {
+        Self::new(value, false)
+    }
+}
+
+impl<T> Neg for SignedBigNum<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.magnitude, !self.negative)
+    }
+}
+
+impl<T> Add for SignedBigNum<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            Self::new(self.magnitude + rhs.magnitude, self.negative)
+        } else if self.magnitude >= rhs.magnitude {
+            Self::new(self.magnitude - rhs.magnitude, self.negative)
+        } else {
+            Self::new(rhs.magnitude - self.magnitude, rhs.negative)
+        }
+    }
+}
+
+impl<T> AddAssign for SignedBigNum<T>
+where
+    T: Base,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for SignedBigNum<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T> SubAssign for SignedBigNum<T>
+where
+    T: Base,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Mul for SignedBigNum<T>
+where
+    T: Base,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.magnitude * rhs.magnitude,
+            self.negative != rhs.negative,
+        )
+    }
+}
+
+impl<T> MulAssign for SignedBigNum<T>
+where
+    T: Base,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{BigNumBin, BigNumDec, DEC_SIG_RANGE};
+
+    #[test]
+    fn signed_sub_test() {
+        type BigNum = BigNumDec;
+        type Signed = SignedBigNum<crate::Decimal>;
+
+        let a = Signed::from(BigNum::from(10));
+        let b = Signed::from(BigNum::from(25));
+
+        assert_eq!(a - b, Signed::new(BigNum::from(15), true));
+        assert_eq!(b - a, Signed::from(BigNum::from(15)));
+        assert_eq!(a - a, Signed::from(BigNum::from(0)));
+        assert_eq!((a - b) - b, Signed::new(BigNum::from(40), true));
+        assert_eq!((a - b) + b, a);
+
+        let big = Signed::from(BigNum::new(DEC_SIG_RANGE.0, 1000));
+        let diff = a - big;
+        assert!(diff.is_negative());
+        assert_eq!(diff.magnitude(), BigNum::new(DEC_SIG_RANGE.0, 1000));
+
+        let mut acc = Signed::from(BigNum::from(5));
+        acc -= b;
+        acc += a;
+        assert_eq!(acc, Signed::new(BigNum::from(10), true));
+    }
+
+    #[test]
+    fn signed_mul_test() {
+        type BigNum = BigNumBin;
+        type Signed = SignedBigNum<crate::Binary>;
+
+        let pos = Signed::from(BigNum::from(6));
+        let neg = Signed::new(BigNum::from(7), true);
+
+        assert_eq!(pos * neg, Signed::new(BigNum::from(42), true));
+        assert_eq!(neg * pos, Signed::new(BigNum::from(42), true));
+        assert_eq!(neg * neg, Signed::from(BigNum::from(49)));
+        assert_eq!(pos * pos, Signed::from(BigNum::from(36)));
+        assert_eq!(
+            neg * Signed::from(BigNum::from(0)),
+            Signed::from(BigNum::from(0))
+        );
+
+        let mut acc = neg;
+        acc *= neg;
+        acc *= neg;
+        assert_eq!(acc, Signed::new(BigNum::from(343), true));
+    }
+
+    #[test]
+    fn signed_neg_zero_test() {
+        type Signed = SignedBigNum<crate::Decimal>;
+
+        let zero = Signed::from(BigNumDec::from(0));
+
+        assert_eq!(-zero, zero);
+        assert!(!(-zero).is_negative());
+        assert_eq!(Signed::new(BigNumDec::from(0), true), zero);
+        assert_eq!(
+            -(-Signed::from(BigNumDec::from(3))),
+            Signed::from(BigNumDec::from(3))
+        );
+    }
+}
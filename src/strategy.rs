@@ -0,0 +1,135 @@
+//! This module contains `proptest` strategies for generating `BigNum` values. Every
+//! generated value is normalized (see `BigNumBase::is_normalized`), and magnitudes are
+//! picked uniformly so small values are as likely to come up as huge ones. Failing cases
+//! shrink towards smaller magnitudes and significands.
+//!
+//! # Examples
+//! ```
+//! use bignumbe_rs::{strategy::bignum_between, BigNumBin};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(n in bignum_between(BigNumBin::from(1), BigNumBin::new(1, 100)))| {
+//!     prop_assert!(n * 2 > n);
+//! });
+//! ```
+
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{traits::Pred, Base, BigNumBase, SigRange};
+
+/// Generates values across the whole representable range. The magnitude is uniform over
+/// `[0, u64::MAX]`, so the exponent of almost every value is huge. Use `bignum_between` if
+/// you need values in a narrower range
+pub fn any_bignum<T: Base>() -> impl Strategy<Value = BigNumBase<T>> {
+    bignum_between(BigNumBase::from(0), BigNumBase::max_value())
+}
+
+/// Generates values in the inclusive range `[lo, hi]`. Each magnitude in the range is
+/// equally likely, and then a value is picked uniformly among those with that magnitude.
+/// Panics if `lo > hi`
+pub fn bignum_between<T: Base>(
+    lo: BigNumBase<T>,
+    hi: BigNumBase<T>,
+) -> impl Strategy<Value = BigNumBase<T>> {
+    if lo > hi {
+        panic!("Unable to create a strategy with lo > hi");
+    }
+
+    (lo.magnitude()..=hi.magnitude()).prop_flat_map(move |mag| {
+        let low = if mag == lo.magnitude() {
+            lo
+        } else {
+            BigNumBase::power_of_base(mag)
+        };
+        let high = if mag == hi.magnitude() {
+            hi
+        } else {
+            BigNumBase::power_of_base(mag + 1).pred()
+        };
+
+        between_exps(low, high)
+    })
+}
+
+// Only the saturated top magnitude spans more than one exponent, but handling it in
+// general is simpler than special-casing it
+fn between_exps<T: Base>(
+    low: BigNumBase<T>,
+    high: BigNumBase<T>,
+) -> impl Strategy<Value = BigNumBase<T>> {
+    let base = high.base;
+    let SigRange(min_sig, max_sig) = base.sig_range();
+
+    (low.exp..=high.exp).prop_flat_map(move |exp| {
+        let sig_lo = if exp == low.exp { low.sig } else { min_sig };
+        let sig_hi = if exp == high.exp { high.sig } else { max_sig };
+
+        (sig_lo..=sig_hi).prop_map(move |sig| BigNumBase { sig, exp, base })
+    })
+}
+
+/// Allows `any::<BigNumBase<T>>()` and `BigNumBase<T>` arguments in `proptest!`, using
+/// `any_bignum`
+impl<T> Arbitrary for BigNumBase<T>
+where
+    T: Base + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_bignum().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{BigNumBin, BigNumDec, Decimal};
+
+    proptest! {
+        #[test]
+        fn any_bignum_valid_test(n in any::<BigNumDec>()) {
+            prop_assert!(n.is_normalized());
+        }
+
+        #[test]
+        fn bignum_between_test(
+            n in bignum_between(BigNumDec::from(25), BigNumDec::new(1234, 30))
+        ) {
+            prop_assert!(n.is_normalized());
+            prop_assert!(n >= BigNumDec::from(25) && n <= BigNumDec::new(1234, 30));
+        }
+
+        #[test]
+        fn bignum_between_top_test(
+            n in bignum_between(BigNumBase::<Decimal>::new(1, u64::MAX - 30), BigNumDec::max_value())
+        ) {
+            prop_assert!(n.is_normalized());
+            prop_assert!(n >= BigNumDec::new(1, u64::MAX - 30));
+        }
+
+        // Adding a smaller value can push the sum to the next exponent, which loses a
+        // digit of `a`, so allow for one step in each direction
+        #[test]
+        fn add_sub_test((a, b) in any::<BigNumBin>().prop_flat_map(|a| {
+            (Just(a), bignum_between(BigNumBin::from(0), a))
+        })) {
+            prop_assert!(((a + b) - b).fuzzy_eq(a, 4));
+        }
+    }
+
+    #[test]
+    fn bignum_between_exact_test() {
+        let n = BigNumDec::new(1234, 40);
+
+        proptest!(|(m in bignum_between(n, n))| {
+            prop_assert_eq!(m, n);
+        });
+    }
+}
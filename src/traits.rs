@@ -4,7 +4,7 @@ use crate::{Base, BigNumBase, SigRange};
 
 /// This trait gets the very next valid value of a type. Mainly for `BigNum`, since adding
 /// one often doesn't result in a changing value. This is provided for contexts where you
-/// need to increase the value easily
+/// need to increase the value easily. Panics if called on the maximum value
 pub trait Succ {
     fn succ(self) -> Self;
 }
@@ -26,7 +26,9 @@ where
         if self.sig == max_sig {
             Self {
                 sig: min_sig,
-                exp: self.exp + 1,
+                exp: self.exp.checked_add(1).unwrap_or_else(|| {
+                    panic!("Cannot get the successor of the maximum value {:?}", self)
+                }),
                 base: self.base,
             }
         } else {
@@ -69,6 +71,145 @@ where
     }
 }
 
+/// This is a stable stand-in for the nightly `std::iter::Step` trait. It treats the
+/// valid values of a type as a sequence, so `forward_checked(x, 1)` is `x.succ()` and
+/// `steps_between` counts how many valid values lie between two values. Since the real
+/// trait is unstable `start..end` can't be iterated directly, use `BigNumRange` instead.
+pub trait Step: Sized {
+    /// Returns the number of successor steps needed to get from `start` to `end`, or
+    /// `None` if `start > end` or the count doesn't fit in a `u64`
+    fn steps_between(start: &Self, end: &Self) -> Option<u64>;
+
+    /// Returns the value `count` steps after `start`, or `None` if it isn't representable
+    fn forward_checked(start: Self, count: u64) -> Option<Self>;
+
+    /// Returns the value `count` steps before `start`, or `None` if it would be below 0
+    fn backward_checked(start: Self, count: u64) -> Option<Self>;
+}
+
+impl<T> BigNumBase<T>
+where
+    T: Base,
+{
+    // Position of this value in the sequence of all valid values. Compact values
+    // `[0, max_sig]` come first, followed by `max_sig - min_sig + 1` values per exponent.
+    // The result fits in a u128 since there are at most 2^64 values per exponent
+    fn step_index(self) -> u128 {
+        let SigRange(min_sig, max_sig) = self.base.sig_range();
+
+        if self.exp == 0 {
+            self.sig as u128
+        } else {
+            let width = (max_sig - min_sig) as u128 + 1;
+
+            max_sig as u128 + 1 + (self.exp - 1) as u128 * width + (self.sig - min_sig) as u128
+        }
+    }
+
+    fn from_step_index(index: u128) -> Option<Self> {
+        let base = T::new();
+        let SigRange(min_sig, max_sig) = base.sig_range();
+
+        if index <= max_sig as u128 {
+            return Some(Self {
+                sig: index as u64,
+                exp: 0,
+                base,
+            });
+        }
+
+        let width = (max_sig - min_sig) as u128 + 1;
+        let rem = index - (max_sig as u128 + 1);
+        let exp = u64::try_from(rem / width + 1).ok()?;
+
+        Some(Self {
+            sig: min_sig + (rem % width) as u64,
+            exp,
+            base,
+        })
+    }
+}
+
+impl<T> Step for BigNumBase<T>
+where
+    T: Base,
+{
+    fn steps_between(start: &Self, end: &Self) -> Option<u64> {
+        end.step_index()
+            .checked_sub(start.step_index())
+            .and_then(|steps| u64::try_from(steps).ok())
+    }
+
+    fn forward_checked(start: Self, count: u64) -> Option<Self> {
+        start
+            .step_index()
+            .checked_add(count as u128)
+            .and_then(Self::from_step_index)
+    }
+
+    fn backward_checked(start: Self, count: u64) -> Option<Self> {
+        start
+            .step_index()
+            .checked_sub(count as u128)
+            .and_then(Self::from_step_index)
+    }
+}
+
+/// An iterator over every valid value in the half-open range `[start, end)`, in
+/// ascending order. This is the stable equivalent of `start..end`.
+///
+/// # Examples
+/// ```
+/// use bignumbe_rs::{traits::BigNumRange, BigNumBin};
+///
+/// let range = BigNumRange::new(BigNumBin::from(0), BigNumBin::from(100));
+///
+/// assert_eq!(range.count(), 100);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BigNumRange<T>
+where
+    T: Base,
+{
+    start: BigNumBase<T>,
+    end: BigNumBase<T>,
+}
+
+impl<T> BigNumRange<T>
+where
+    T: Base,
+{
+    pub fn new(start: BigNumBase<T>, end: BigNumBase<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T> Iterator for BigNumRange<T>
+where
+    T: Base,
+{
+    type Item = BigNumBase<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let curr = self.start;
+            self.start = self.start.succ();
+
+            Some(curr)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match Step::steps_between(&self.start, &self.end).map(usize::try_from) {
+            Some(Ok(n)) => (n, Some(n)),
+            Some(Err(_)) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
 pub trait BigNumPow<T>
 where
     T: Base,
@@ -142,6 +283,50 @@ mod tests {
         assert_eq_bignum!(BigNum::new(min_sig, 2).pred(), BigNum::new(max_sig, 1));
     }
 
+    #[test]
+    fn test_step() {
+        type BigNum = BigNumBase<Binary>;
+        let SigRange(min_sig, max_sig) = Binary::calculate_ranges().1;
+
+        let (start, end) = (BigNum::from(0), BigNum::from(100));
+        assert_eq!(BigNum::steps_between(&start, &end), Some(100));
+        assert_eq!(BigNumRange::new(start, end).count(), 100);
+        assert_eq!(BigNum::steps_between(&end, &start), None);
+
+        // Crossing an exponent boundary
+        let (start, end) = (BigNum::from(max_sig - 5), BigNum::new(min_sig + 5, 1));
+        assert_eq!(BigNum::steps_between(&start, &end), Some(11));
+        assert_eq!(BigNumRange::new(start, end).count(), 11);
+        assert_eq!(BigNumRange::new(start, end).size_hint(), (11, Some(11)));
+
+        let (start, end) = (BigNum::new(max_sig, 10), BigNum::new(min_sig, 12));
+        assert_eq!(
+            BigNum::steps_between(&start, &end),
+            Some(max_sig - min_sig + 2)
+        );
+
+        for n in [
+            BigNum::from(0),
+            BigNum::from(max_sig),
+            BigNum::new(min_sig, 1),
+            BigNum::new(max_sig, 1234),
+        ] {
+            assert_eq!(BigNum::forward_checked(n, 1), Some(n.succ()));
+            assert_eq!(BigNum::backward_checked(n.succ(), 1), Some(n));
+            assert_eq!(BigNum::steps_between(&n, &n.succ()), Some(1));
+        }
+
+        assert_eq!(BigNum::backward_checked(BigNum::from(0), 1), None);
+        assert_eq!(
+            BigNum::forward_checked(BigNum::new(max_sig, u64::MAX), 1),
+            None
+        );
+        assert_eq!(
+            BigNum::steps_between(&BigNum::from(0), &BigNum::new(max_sig, 10)),
+            None
+        );
+    }
+
     #[test]
     fn test_bignum_pow() {
         type BigNum = BigNumBase<Binary>;
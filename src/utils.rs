@@ -0,0 +1,281 @@
+//! This module contains helper functions that operate on collections of `BigNum` values
+//! rather than on individual numbers, along with some helpers for implementing `Base`.
+
+use crate::{Base, BigNumBase};
+
+/// Returns a vector where each element is the max of the corresponding elements in `a`
+/// and `b`. Useful for merging two sets of stats. Panics if the slices have different
+/// lengths, since silently truncating would likely hide a bug
+pub fn elementwise_max<T: Base>(a: &[BigNumBase<T>], b: &[BigNumBase<T>]) -> Vec<BigNumBase<T>> {
+    elementwise(a, b, Ord::max)
+}
+
+/// Returns a vector where each element is the min of the corresponding elements in `a`
+/// and `b`. Panics if the slices have different lengths, since silently truncating would
+/// likely hide a bug
+pub fn elementwise_min<T: Base>(a: &[BigNumBase<T>], b: &[BigNumBase<T>]) -> Vec<BigNumBase<T>> {
+    elementwise(a, b, Ord::min)
+}
+
+fn elementwise<T: Base>(
+    a: &[BigNumBase<T>],
+    b: &[BigNumBase<T>],
+    f: fn(BigNumBase<T>, BigNumBase<T>) -> BigNumBase<T>,
+) -> Vec<BigNumBase<T>> {
+    if a.len() != b.len() {
+        panic!(
+            "Unable to combine slices of different lengths: {} and {}",
+            a.len(),
+            b.len()
+        );
+    }
+
+    a.iter().zip(b).map(|(&l, &r)| f(l, r)).collect()
+}
+
+/// Interpolates across a sequence of keyframes. `t` is clamped to `[0, 1]` and mapped
+/// evenly across the gaps between keyframes, then the two keyframes surrounding that
+/// point are interpolated linearly. E.g. with 3 keyframes, `t = 0.5` returns the middle
+/// one and `t = 0.75` returns the point halfway between the last two. Panics if
+/// `keyframes` is empty
+pub fn multi_lerp<T: Base>(keyframes: &[BigNumBase<T>], t: f64) -> BigNumBase<T> {
+    if keyframes.is_empty() {
+        panic!("Unable to interpolate across an empty set of keyframes");
+    } else if keyframes.len() == 1 {
+        return keyframes[0];
+    }
+
+    let segments = keyframes.len() - 1;
+    let pos = t.clamp(0.0, 1.0) * segments as f64;
+    let i = (pos.floor() as usize).min(segments - 1);
+
+    keyframes[i].lerp(keyframes[i + 1], pos - i as f64)
+}
+
+/// Sums `values` while keeping track of how much was lost to precision. Returns the sum
+/// along with the total of every term that was too small to change the running total
+/// when added (i.e. was below its last place). If the second value is large relative to
+/// the first you may want to sort the values in ascending order first, so that small
+/// terms get combined before the total grows past them
+pub fn sum_with_floor<T: Base>(values: &[BigNumBase<T>]) -> (BigNumBase<T>, BigNumBase<T>) {
+    let mut sum = BigNumBase::from(0);
+    let mut lost = BigNumBase::from(0);
+
+    for &v in values {
+        let next = sum + v;
+
+        if next == sum {
+            lost += v;
+        }
+
+        sum = next;
+    }
+
+    (sum, lost)
+}
+
+/// Lazily yields the geometric sequence `start, start * ratio, start * ratio^2, ...`,
+/// stopping once a term would overflow. Each term is computed from the previous one, so
+/// the drift of each multiplication carries forward and the `n`th term can be off by
+/// roughly `n` in the last digit of its significand. See `geometric_exact` if that matters
+pub fn geometric<T: Base>(
+    start: BigNumBase<T>,
+    ratio: BigNumBase<T>,
+) -> impl Iterator<Item = BigNumBase<T>> {
+    std::iter::successors(Some(start), move |&term| term.checked_mul(ratio))
+}
+
+/// Same as `geometric`, but computes each term as `start * ratio.pow(n)` so errors don't
+/// accumulate across terms. This costs `O(log n)` multiplications per term instead of one
+pub fn geometric_exact<T: Base>(
+    start: BigNumBase<T>,
+    ratio: BigNumBase<T>,
+) -> impl Iterator<Item = BigNumBase<T>> {
+    (0..=u32::MAX).map_while(move |n| ratio.checked_pow(n)?.checked_mul(start))
+}
+
+/// Returns the highest `x` such that `sig >= powers[x]`, found with a binary search. If
+/// `powers` holds every power of a base that fits in a `u64`, starting with 1, this is the
+/// same as `sig.ilog(base)`, and is much faster for bases that aren't powers of 2 or 10.
+/// Panics if `sig` is 0
+pub fn mag_from_powers(sig: u64, powers: &[u64]) -> u32 {
+    if sig == 0 {
+        panic!("Unable to find the magnitude of 0");
+    }
+
+    (powers.partition_point(|&p| p <= sig) - 1) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{create_default_base, BigNumDec, ExpRange, SigRange};
+
+    use crate::BigNumBin;
+
+    #[test]
+    fn elementwise_test() {
+        type BigNum = BigNumBin;
+
+        let a = [
+            BigNum::from(1),
+            BigNum::new(1 << 63, 10),
+            BigNum::from(0),
+            BigNum::new(u64::MAX, 100),
+        ];
+        let b = [
+            BigNum::from(2),
+            BigNum::new(1 << 63, 9),
+            BigNum::from(0),
+            BigNum::new(1 << 63, 101),
+        ];
+
+        assert_eq!(elementwise_max(&a, &b), vec![b[0], a[1], a[2], b[3]]);
+        assert_eq!(elementwise_min(&a, &b), vec![a[0], b[1], b[2], a[3]]);
+        assert_eq!(elementwise_max::<crate::Binary>(&[], &[]), vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn elementwise_mismatch_test() {
+        type BigNum = BigNumBin;
+
+        elementwise_max(&[BigNum::from(1)], &[BigNum::from(1), BigNum::from(2)]);
+    }
+
+    #[test]
+    fn multi_lerp_test() {
+        type BigNum = BigNumBin;
+
+        let frames = [
+            BigNum::from(0),
+            BigNum::from(1000),
+            BigNum::new(1 << 63, 100),
+            BigNum::from(10),
+        ];
+
+        assert_eq!(multi_lerp(&frames, -1.0), frames[0]);
+        assert_eq!(multi_lerp(&frames, 0.0), frames[0]);
+        assert_eq!(multi_lerp(&frames, 1.0 / 3.0), frames[1]);
+        assert_eq!(multi_lerp(&frames, 2.0 / 3.0), frames[2]);
+        assert_eq!(multi_lerp(&frames, 1.0), frames[3]);
+        assert_eq!(multi_lerp(&frames, 2.0), frames[3]);
+
+        assert_eq!(multi_lerp(&frames, 1.0 / 6.0), BigNum::from(500));
+        assert!(multi_lerp(&frames, 0.5).fuzzy_eq(BigNum::new(1 << 62, 100), 2));
+        assert!(multi_lerp(&frames, 5.0 / 6.0).fuzzy_eq(BigNum::new(1 << 62, 100), 2));
+
+        assert_eq!(multi_lerp(&[BigNum::from(7)], 0.3), BigNum::from(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn multi_lerp_empty_test() {
+        multi_lerp::<crate::Binary>(&[], 0.5);
+    }
+
+    #[test]
+    fn sum_with_floor_test() {
+        type BigNum = BigNumBin;
+
+        let mut values = vec![BigNum::new(1 << 63, 10)];
+        values.extend([BigNum::from(1); 100]);
+        values.push(BigNum::from(1 << 20));
+
+        let (sum, lost) = sum_with_floor(&values);
+
+        assert_eq!(sum, BigNum::new((1 << 63) + (1 << 10), 10));
+        assert_eq!(lost, BigNum::from(100));
+
+        // Sorting ascending lets the small values combine before they're discarded
+        values.sort();
+        let (sum, lost) = sum_with_floor(&values);
+
+        assert_eq!(sum, BigNum::new((1 << 63) + (1 << 10), 10));
+        assert_eq!(lost, BigNum::from(0));
+
+        let exact = [BigNum::from(5), BigNum::from(10), BigNum::from(0)];
+        assert_eq!(sum_with_floor(&exact), (BigNum::from(15), BigNum::from(0)));
+    }
+
+    #[test]
+    fn mag_from_powers_test() {
+        create_default_base!(Base61, 61);
+
+        let ExpRange(_, max_exp) = Base61::calculate_ranges().0;
+        let powers: Vec<u64> = (0..=max_exp).map(Base61::pow).collect();
+
+        for &p in &powers {
+            for sig in [p - 1, p, p + 1] {
+                if sig > 0 {
+                    assert_eq!(mag_from_powers(sig, &powers), sig.ilog(61), "{}", sig);
+                }
+            }
+        }
+        assert_eq!(mag_from_powers(u64::MAX, &powers), u64::MAX.ilog(61));
+    }
+
+    #[test]
+    fn powers_get_mag_test() {
+        const POWERS: [u64; 7] = [
+            1,
+            1000,
+            1000u64.pow(2),
+            1000u64.pow(3),
+            1000u64.pow(4),
+            1000u64.pow(5),
+            1000u64.pow(6),
+        ];
+
+        #[derive(Clone, Copy, Debug)]
+        struct Base1000;
+
+        impl Base for Base1000 {
+            const NUMBER: u32 = 1000;
+
+            fn new() -> Self {
+                Self
+            }
+
+            fn exp_range(&self) -> ExpRange {
+                Self::calculate_ranges().0
+            }
+
+            fn sig_range(&self) -> SigRange {
+                Self::calculate_ranges().1
+            }
+
+            fn powers() -> Option<&'static [u64]> {
+                Some(&POWERS)
+            }
+        }
+
+        assert_eq!(Base1000::get_mag(999), 0);
+        assert_eq!(Base1000::get_mag(1000), 1);
+        assert_eq!(Base1000::get_mag(u64::MAX), 6);
+        assert_eq!(BigNumBase::<Base1000>::from(123_456_789).magnitude(), 2);
+    }
+
+    #[test]
+    fn geometric_test() {
+        let start = BigNumDec::new(3 * 10u64.pow(18), 10);
+        let ratio = BigNumDec::from(7);
+
+        let terms: Vec<_> = geometric(start, ratio).take(20).collect();
+        let exact: Vec<_> = geometric_exact(start, ratio).take(20).collect();
+
+        assert_eq!(terms.len(), 20);
+        assert_eq!(terms[0], start);
+        assert_eq!(exact[3], start * 343);
+
+        for (i, (term, exact)) in terms.into_iter().zip(exact).enumerate() {
+            assert!(term.fuzzy_eq(exact, 20), "{}: {:?} vs {:?}", i, term, exact);
+        }
+
+        // Both stop at the last term that fits instead of panicking
+        let top = BigNumDec::new(10u64.pow(18), u64::MAX - 20);
+        assert_eq!(geometric(top, BigNumDec::from(10)).count(), 21);
+        assert_eq!(geometric_exact(top, BigNumDec::from(10)).count(), 21);
+    }
+}